@@ -1,5 +1,6 @@
 /*
-RUST_BACKTRACE=1 RUST_LOG=trace cargo run -p instagram-basic-display-api-demo --bin ig_b_d_a_user_access_token_simple -- 'YOUR_APP_SECRET' 'YOUR_SHORT_LIVED_USER_ACCESS_TOKEN'
+RUST_BACKTRACE=1 RUST_LOG=trace cargo run -p instagram-basic-display-api-demo --bin ig_b_d_a_user_access_token_simple -- 'YOUR_APP_ID' 'YOUR_APP_SECRET' 'YOUR_REDIRECT_URI'
+RUST_BACKTRACE=1 RUST_LOG=trace cargo run -p instagram-basic-display-api-demo --bin ig_b_d_a_user_access_token_simple -- 'YOUR_APP_ID' 'YOUR_APP_SECRET' 'YOUR_REDIRECT_URI' 'YOUR_CODE'
 */
 
 use std::{env, error};
@@ -7,8 +8,9 @@ use std::{env, error};
 use futures_lite::future::block_on;
 use http_api_isahc_client::{Client as _, IsahcClient};
 use instagram_basic_display_api::operations::{
-    EndpointRet, ExchangeSlAccessTokenForLlAccessTokenEndpoint, RefreshAccessTokenEndpoint,
-    UserEndpoint, UserMediasEndpoint,
+    AuthorizationUrl, EndpointRet, ExchangeCodeForShortLivedAccessTokenEndpoint,
+    ExchangeSlAccessTokenForLlAccessTokenEndpoint, RefreshAccessTokenEndpoint, Scope, UserEndpoint,
+    UserMediasEndpoint,
 };
 
 fn main() -> Result<(), Box<dyn error::Error>> {
@@ -18,11 +20,47 @@ fn main() -> Result<(), Box<dyn error::Error>> {
 }
 
 async fn run() -> Result<(), Box<dyn error::Error>> {
-    let app_secret = env::args().nth(1).unwrap();
-    let short_lived_user_access_token = env::args().nth(2).unwrap();
+    let app_id: u64 = env::args().nth(1).unwrap().parse()?;
+    let app_secret = env::args().nth(2).unwrap();
+    let redirect_uri = env::args().nth(3).unwrap();
+    let code = match env::args().nth(4) {
+        Some(x) => x,
+        None => {
+            let url = AuthorizationUrl::new(
+                app_id,
+                &redirect_uri,
+                [Scope::UserProfile, Scope::UserMedia],
+            )
+            .build()?;
+            println!("open {url} , then rerun with the code of the redirect uri");
+            return Ok(());
+        }
+    };
 
     let client = IsahcClient::new()?;
 
+    //
+    let exchange_code_for_short_lived_access_token =
+        ExchangeCodeForShortLivedAccessTokenEndpoint::new(
+            app_id,
+            &app_secret,
+            &redirect_uri,
+            &code,
+        );
+    let ret = client
+        .respond_endpoint(&exchange_code_for_short_lived_access_token)
+        .await?;
+    let short_lived_user_access_token = match &ret {
+        EndpointRet::Ok(ok_json) => {
+            println!("{ok_json:?}");
+
+            ok_json.short_lived_access_token()
+        }
+        EndpointRet::Other(_) => {
+            panic!("{ret:?}");
+        }
+    };
+
     //
     let me = UserEndpoint::me(&short_lived_user_access_token, false);
     let ret = client.respond_endpoint(&me).await?;
@@ -39,7 +77,7 @@ async fn run() -> Result<(), Box<dyn error::Error>> {
     let exchange_sl_access_token_for_ll_access_token =
         ExchangeSlAccessTokenForLlAccessTokenEndpoint::new(
             &app_secret,
-            &short_lived_user_access_token,
+            short_lived_user_access_token,
         );
     let ret = client
         .respond_endpoint(&exchange_sl_access_token_for_ll_access_token)
@@ -1,3 +1,4 @@
 pub mod objects;
 pub mod operations;
+pub mod token_manager;
 pub mod types;
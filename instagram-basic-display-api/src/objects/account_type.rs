@@ -1,17 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default)]
 pub enum AccountType {
     #[serde(rename = "BUSINESS")]
     Business,
     #[serde(rename = "MEDIA_CREATOR")]
     MediaCreator,
     #[serde(rename = "PERSONAL")]
+    #[default]
     Personal,
 }
-
-impl Default for AccountType {
-    fn default() -> Self {
-        Self::Personal
-    }
-}
@@ -0,0 +1,91 @@
+//! https://developers.facebook.com/docs/instagram-basic-display-api/reference/oauth-authorize
+
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use url::{ParseError as UrlParseError, Url};
+
+use super::common::OAUTH_BASE_URL;
+
+//
+#[derive(Debug, Clone)]
+pub struct AuthorizationUrl {
+    client_id: u64,
+    redirect_uri: String,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+impl AuthorizationUrl {
+    pub fn new(
+        app_id: u64,
+        redirect_uri: impl AsRef<str>,
+        scopes: impl IntoIterator<Item = Scope>,
+    ) -> Self {
+        Self {
+            client_id: app_id,
+            redirect_uri: redirect_uri.as_ref().into(),
+            scopes: scopes.into_iter().collect(),
+            state: None,
+        }
+    }
+
+    pub fn with_state(mut self, state: impl AsRef<str>) -> Self {
+        self.state = Some(state.as_ref().into());
+        self
+    }
+
+    pub fn build(&self) -> Result<Url, UrlParseError> {
+        let mut url = Url::parse(format!("{OAUTH_BASE_URL}/oauth/authorize").as_str())?;
+
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id.to_string())
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair(
+                "scope",
+                &self
+                    .scopes
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .append_pair("response_type", "code");
+
+        if let Some(state) = &self.state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+
+        Ok(url)
+    }
+}
+
+//
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    UserProfile,
+    UserMedia,
+    #[serde(other)]
+    Other(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let url = AuthorizationUrl::new(
+            123,
+            "https://example.com/auth/",
+            [Scope::UserProfile, Scope::UserMedia],
+        )
+        .build()
+        .unwrap();
+        assert_eq!(url.as_str(), "https://api.instagram.com/oauth/authorize?client_id=123&redirect_uri=https%3A%2F%2Fexample.com%2Fauth%2F&scope=user_profile%2Cuser_media&response_type=code");
+
+        let url = AuthorizationUrl::new(123, "https://example.com/auth/", [Scope::UserProfile])
+            .with_state("1")
+            .build()
+            .unwrap();
+        assert_eq!(url.as_str(), "https://api.instagram.com/oauth/authorize?client_id=123&redirect_uri=https%3A%2F%2Fexample.com%2Fauth%2F&scope=user_profile&response_type=code&state=1");
+    }
+}
@@ -16,6 +16,7 @@ pub const URL_PERCENT_ENCODE_ASCII_SET: &AsciiSet = &NON_ALPHANUMERIC
     .remove(b')');
 
 pub const BASE_URL: &str = "https://graph.instagram.com";
+pub const OAUTH_BASE_URL: &str = "https://api.instagram.com";
 pub const API_VERSION: &str = "v15.0";
 
 use crate::objects::ResponseErrorBody;
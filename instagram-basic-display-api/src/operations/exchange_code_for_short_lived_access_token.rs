@@ -0,0 +1,135 @@
+//! https://developers.facebook.com/docs/instagram-basic-display-api/reference/oauth-access-token
+
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, CONTENT_TYPE, USER_AGENT},
+        Method,
+    },
+    Body, Endpoint, Request, Response,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+use url::{form_urlencoded, Url};
+
+use super::common::{endpoint_parse_response, EndpointError, EndpointRet, OAUTH_BASE_URL};
+use crate::types::ShortLivedUserAccessToken;
+
+//
+#[derive(Debug, Clone)]
+pub struct ExchangeCodeForShortLivedAccessTokenEndpoint {
+    client_id: u64,
+    client_secret: String,
+    redirect_uri: String,
+    code: String,
+}
+impl ExchangeCodeForShortLivedAccessTokenEndpoint {
+    pub fn new(
+        app_id: u64,
+        app_secret: impl AsRef<str>,
+        redirect_uri: impl AsRef<str>,
+        code: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            client_id: app_id,
+            client_secret: app_secret.as_ref().into(),
+            redirect_uri: redirect_uri.as_ref().into(),
+            // "#_" is appended to the redirect uri, it is not part of the code
+            code: code.as_ref().trim_end_matches("#_").into(),
+        }
+    }
+}
+
+impl Endpoint for ExchangeCodeForShortLivedAccessTokenEndpoint {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ExchangeCodeForShortLivedAccessTokenResponseBody>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = Url::parse(format!("{OAUTH_BASE_URL}/oauth/access_token").as_str())
+            .map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        let body = form_urlencoded::Serializer::new(String::new())
+            .append_pair("client_id", &self.client_id.to_string())
+            .append_pair("client_secret", &self.client_secret)
+            .append_pair("grant_type", "authorization_code")
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("code", &self.code)
+            .finish();
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-basic-display-api")
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body.into_bytes())
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        // Errors are {"error_type","code","error_message"}, they are kept as the raw body
+        endpoint_parse_response(response)
+    }
+}
+
+//
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExchangeCodeForShortLivedAccessTokenResponseBody {
+    pub access_token: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub user_id: u64,
+}
+
+impl ExchangeCodeForShortLivedAccessTokenResponseBody {
+    pub fn short_lived_access_token(&self) -> ShortLivedUserAccessToken {
+        ShortLivedUserAccessToken::from(&self.access_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_request() {
+        let req = ExchangeCodeForShortLivedAccessTokenEndpoint::new(
+            123,
+            "SECRET",
+            "https://example.com/auth/",
+            "CODE#_",
+        )
+        .render_request()
+        .unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(req.uri(), "https://api.instagram.com/oauth/access_token");
+        assert_eq!(
+            req.headers().get(CONTENT_TYPE).unwrap(),
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(
+            req.body(),
+            b"client_id=123&client_secret=SECRET&grant_type=authorization_code&redirect_uri=https%3A%2F%2Fexample.com%2Fauth%2F&code=CODE"
+        );
+    }
+
+    #[test]
+    fn test_de_response_body() {
+        let body =
+            serde_json::from_str::<ExchangeCodeForShortLivedAccessTokenResponseBody>(include_str!(
+                "../../tests/response_body_files/exchange_code_for_short_lived_access_token_ok.json"
+            ))
+            .unwrap();
+
+        assert_eq!(body.user_id, 17841405793187218);
+        assert_eq!(
+            body.short_lived_access_token().inner(),
+            "IGQVJYS0k8V1ZAzRXFpeGRMVGp3SDF4ZAXdhV3hJbXlMVUpEXzVGdTlZAWjNPWWVvNlpZAV0tpOUl2YzdEQ1V6V1ZArbWRmQkRKM1hJYlBHQUFmQXhmdUdzUXhpQkE5cnU5ZAHA1Mk9wUXdZAVmlGUlR4OGlXS3g2ZAFpmb2h4ZAEFzemlmV2tOOXZAFR09TeklhdWVqNnhzNHp6ZAEcZD"
+        );
+    }
+}
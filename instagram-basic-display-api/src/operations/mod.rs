@@ -3,11 +3,17 @@ pub mod common;
 pub use common::EndpointRet;
 
 //
+pub mod authorization_url;
+pub mod exchange_code_for_short_lived_access_token;
 pub mod exchange_sl_access_token_for_ll_access_token;
 pub mod refresh_access_token;
 pub mod user;
 pub mod user_medias;
 
+pub use authorization_url::{AuthorizationUrl, Scope};
+pub use exchange_code_for_short_lived_access_token::{
+    ExchangeCodeForShortLivedAccessTokenEndpoint, ExchangeCodeForShortLivedAccessTokenResponseBody,
+};
 pub use exchange_sl_access_token_for_ll_access_token::{
     ExchangeSlAccessTokenForLlAccessTokenEndpoint,
     ExchangeSlAccessTokenForLlAccessTokenResponseBody,
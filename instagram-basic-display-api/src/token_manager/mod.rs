@@ -0,0 +1,459 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-basic-display-api/guides/long-lived-access-tokens)
+
+use core::time::Duration;
+use std::time::SystemTime;
+
+use chrono::{DateTime, Utc};
+use http_api_client::{Client, ClientRespondEndpointError};
+use http_api_client_endpoint::{http::StatusCode, Body};
+
+use crate::{
+    objects::ResponseErrorBody,
+    operations::{
+        common::EndpointError, EndpointRet, ExchangeSlAccessTokenForLlAccessTokenResponseBody,
+        RefreshAccessTokenEndpoint,
+    },
+    types::LongLivedUserAccessToken,
+};
+
+pub mod store;
+pub mod token;
+
+pub use store::{JsonFileTokenStore, JsonFileTokenStoreError, MemoryTokenStore, TokenStore};
+pub use token::ManagedToken;
+
+//
+pub const REFRESH_BEFORE_DEFAULT: Duration = Duration::from_secs(3600 * 24 * 7);
+pub const PUT_CHUNK_SIZE_DEFAULT: usize = 100;
+
+//
+#[derive(Debug)]
+pub struct TokenManager<S> {
+    store: S,
+    refresh_before: Duration,
+    put_chunk_size: usize,
+}
+
+impl<S> TokenManager<S>
+where
+    S: TokenStore,
+{
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            refresh_before: REFRESH_BEFORE_DEFAULT,
+            put_chunk_size: PUT_CHUNK_SIZE_DEFAULT,
+        }
+    }
+
+    // Refresh tokens expiring within this duration
+    pub fn with_refresh_before(mut self, value: Duration) -> Self {
+        self.refresh_before = value;
+        self
+    }
+
+    // Number of refreshed tokens refresh_all_due puts at once, clamped to 1
+    pub fn with_put_chunk_size(mut self, value: usize) -> Self {
+        self.put_chunk_size = value.max(1);
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn insert(&self, key: &str, token: &ManagedToken) -> Result<(), S::Error> {
+        self.store.put(key, token)
+    }
+
+    pub fn insert_from_exchange_response_body(
+        &self,
+        key: &str,
+        body: &ExchangeSlAccessTokenForLlAccessTokenResponseBody,
+    ) -> Result<ManagedToken, S::Error> {
+        let token = ManagedToken::from_exchange_response_body(body, now());
+        self.store.put(key, &token)?;
+        Ok(token)
+    }
+
+    // None if missing or expired
+    pub fn access_token(&self, key: &str) -> Result<Option<LongLivedUserAccessToken>, S::Error> {
+        Ok(self
+            .store
+            .get(key)?
+            .filter(|token| !token.is_expired(now()))
+            .map(|token| token.access_token()))
+    }
+
+    pub async fn refresh_if_due<C>(
+        &self,
+        client: &C,
+        key: &str,
+    ) -> Result<TokenRefreshOutcome, TokenManagerError<C::RespondError, S::Error>>
+    where
+        C: Client + Send + Sync,
+    {
+        let outcome = self.refresh_if_due_without_put(client, key).await?;
+        if let TokenRefreshOutcome::Refreshed(token) = &outcome {
+            self.store
+                .put(key, token)
+                .map_err(TokenManagerError::StoreFailed)?;
+        }
+        Ok(outcome)
+    }
+
+    async fn refresh_if_due_without_put<C>(
+        &self,
+        client: &C,
+        key: &str,
+    ) -> Result<TokenRefreshOutcome, TokenManagerError<C::RespondError, S::Error>>
+    where
+        C: Client + Send + Sync,
+    {
+        let token = self
+            .store
+            .get(key)
+            .map_err(TokenManagerError::StoreFailed)?
+            .ok_or(TokenManagerError::TokenMissing)?;
+
+        let now = now();
+        if token.is_expired(now) {
+            return Ok(TokenRefreshOutcome::Expired);
+        }
+        if !token.is_refresh_due(now, self.refresh_before) {
+            return Ok(TokenRefreshOutcome::NotDue);
+        }
+
+        let refresh_access_token = RefreshAccessTokenEndpoint::new(token.access_token());
+        let token = match client
+            .respond_endpoint(&refresh_access_token)
+            .await
+            .map_err(TokenManagerError::RespondEndpointFailed)?
+        {
+            EndpointRet::Ok(ok_json) => ManagedToken::from_refresh_response_body(&ok_json, now),
+            EndpointRet::Other((status, body)) => {
+                return Err(TokenManagerError::EndpointRetOther(status, body))
+            }
+        };
+
+        Ok(TokenRefreshOutcome::Refreshed(token))
+    }
+
+    // Sequential, one failure does not stop the others.
+    // Refreshed tokens are put to the store in chunks of put_chunk_size, a failed put does not
+    // stop the run, the tokens of that chunk are still returned as Refreshed in rets.
+    pub async fn refresh_all_due<C>(
+        &self,
+        client: &C,
+    ) -> Result<TokenRefreshAllDueOutput<C::RespondError, S::Error>, S::Error>
+    where
+        C: Client + Send + Sync,
+    {
+        let mut output = TokenRefreshAllDueOutput {
+            rets: vec![],
+            put_failures: vec![],
+        };
+        let mut tokens_refreshed = vec![];
+        for key in self.store.keys()? {
+            let ret = self.refresh_if_due_without_put(client, &key).await;
+            if let Ok(TokenRefreshOutcome::Refreshed(token)) = &ret {
+                tokens_refreshed.push((key.to_owned(), token.to_owned()));
+            }
+            output.rets.push((key, ret));
+
+            if tokens_refreshed.len() >= self.put_chunk_size {
+                self.put_chunk(&mut tokens_refreshed, &mut output.put_failures);
+            }
+        }
+        if !tokens_refreshed.is_empty() {
+            self.put_chunk(&mut tokens_refreshed, &mut output.put_failures);
+        }
+        Ok(output)
+    }
+
+    fn put_chunk(
+        &self,
+        tokens: &mut Vec<(String, ManagedToken)>,
+        put_failures: &mut Vec<(Vec<String>, S::Error)>,
+    ) {
+        if let Err(err) = self.store.put_many(tokens) {
+            put_failures.push((tokens.iter().map(|(key, _)| key.to_owned()).collect(), err));
+        }
+        tokens.clear();
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    SystemTime::now().into()
+}
+
+//
+#[derive(Debug)]
+pub struct TokenRefreshAllDueOutput<RE, SE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+    SE: std::error::Error + Send + Sync + 'static,
+{
+    #[allow(clippy::type_complexity)]
+    pub rets: Vec<(
+        String,
+        Result<TokenRefreshOutcome, TokenManagerError<RE, SE>>,
+    )>,
+    // Keys of the refreshed tokens that could not be put, put them again from rets
+    pub put_failures: Vec<(Vec<String>, SE)>,
+}
+
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenRefreshOutcome {
+    Refreshed(ManagedToken),
+    NotDue,
+    // Can not be refreshed anymore, the user must authorize again
+    Expired,
+}
+
+//
+#[derive(thiserror::Error, Debug)]
+pub enum TokenManagerError<RE, SE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+    SE: std::error::Error + Send + Sync + 'static,
+{
+    #[error("StoreFailed {0}")]
+    StoreFailed(SE),
+    #[error("TokenMissing")]
+    TokenMissing,
+    #[error("RespondEndpointFailed {0}")]
+    RespondEndpointFailed(ClientRespondEndpointError<RE, EndpointError, EndpointError>),
+    #[error("EndpointRetOther {0} {1:?}")]
+    EndpointRetOther(StatusCode, Result<ResponseErrorBody, Body>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::VecDeque, io::Error as IoError, sync::Mutex};
+
+    use chrono::Duration as ChronoDuration;
+    use http_api_client::async_trait;
+    use http_api_client_endpoint::{Request, Response};
+
+    //
+    #[derive(Debug, Default)]
+    struct MockClient {
+        responses: Mutex<VecDeque<(StatusCode, &'static str)>>,
+        requests: Mutex<Vec<Request<Body>>>,
+    }
+
+    #[async_trait]
+    impl Client for MockClient {
+        type RespondError = IoError;
+
+        async fn respond(&self, request: Request<Body>) -> Result<Response<Body>, IoError> {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| IoError::other("no more responses"))?;
+            Ok(Response::builder()
+                .status(status)
+                .body(body.as_bytes().to_vec())
+                .unwrap())
+        }
+    }
+
+    fn token(access_token: &str, age_days: i64) -> ManagedToken {
+        ManagedToken::new(access_token, now() - ChronoDuration::days(age_days), None)
+    }
+
+    #[test]
+    fn test_refresh_if_due() {
+        futures_lite::future::block_on(async {
+            let client = MockClient {
+                responses: Mutex::new(
+                    vec![(
+                        StatusCode::OK,
+                        include_str!(
+                            "../../tests/response_body_files/refresh_access_token_ok.json"
+                        ),
+                    )]
+                    .into(),
+                ),
+                ..Default::default()
+            };
+            let manager = TokenManager::new(MemoryTokenStore::new());
+            manager.insert("fresh", &token("TOKEN_FRESH", 0)).unwrap();
+            manager.insert("due", &token("TOKEN_DUE", 55)).unwrap();
+            manager
+                .insert("expired", &token("TOKEN_EXPIRED", 61))
+                .unwrap();
+
+            assert_eq!(
+                manager.refresh_if_due(&client, "fresh").await.unwrap(),
+                TokenRefreshOutcome::NotDue
+            );
+            assert_eq!(
+                manager.refresh_if_due(&client, "expired").await.unwrap(),
+                TokenRefreshOutcome::Expired
+            );
+            assert_eq!(manager.access_token("expired").unwrap(), None);
+            assert!(matches!(
+                manager.refresh_if_due(&client, "missing").await,
+                Err(TokenManagerError::TokenMissing)
+            ));
+            assert!(client.requests.lock().unwrap().is_empty());
+
+            match manager.refresh_if_due(&client, "due").await.unwrap() {
+                TokenRefreshOutcome::Refreshed(token) => {
+                    assert_eq!(token.access_token, "x*144");
+                    assert_eq!(token.expires_in, 5183828);
+                }
+                x => panic!("{x:?}"),
+            }
+            assert_eq!(
+                manager.access_token("due").unwrap(),
+                Some(LongLivedUserAccessToken::from("x*144"))
+            );
+            assert_eq!(
+                client.requests.lock().unwrap()[0].uri(),
+                "https://graph.instagram.com/refresh_access_token?grant_type=ig_refresh_token&access_token=TOKEN_DUE"
+            );
+        })
+    }
+
+    #[test]
+    fn test_refresh_all_due() {
+        futures_lite::future::block_on(async {
+            let client = MockClient {
+                responses: Mutex::new(
+                    vec![
+                        (
+                            StatusCode::BAD_REQUEST,
+                            include_str!(
+                                "../../tests/response_body_files/exchange_sl_access_token_for_ll_access_token_err__400.json"
+                            ),
+                        ),
+                        (
+                            StatusCode::OK,
+                            include_str!(
+                                "../../tests/response_body_files/refresh_access_token_ok.json"
+                            ),
+                        ),
+                    ]
+                    .into(),
+                ),
+                ..Default::default()
+            };
+            let manager = TokenManager::new(MemoryTokenStore::new())
+                .with_refresh_before(Duration::from_secs(3600 * 24 * 30));
+            manager.insert("1", &token("TOKEN_1", 10)).unwrap();
+            manager.insert("2", &token("TOKEN_2", 40)).unwrap();
+            manager.insert("3", &token("TOKEN_3", 45)).unwrap();
+
+            let output = manager.refresh_all_due(&client).await.unwrap();
+            assert!(output.put_failures.is_empty());
+            let rets = output.rets;
+            assert_eq!(rets.len(), 3);
+            assert_eq!(rets[0].0, "1");
+            assert!(matches!(rets[0].1, Ok(TokenRefreshOutcome::NotDue)));
+            assert_eq!(rets[1].0, "2");
+            match &rets[1].1 {
+                Err(TokenManagerError::EndpointRetOther(status, Ok(err_body))) => {
+                    assert_eq!(*status, StatusCode::BAD_REQUEST);
+                    assert!(err_body.error.is_access_token_session_has_expired());
+                }
+                x => panic!("{x:?}"),
+            }
+            // Kept as is
+            assert_eq!(
+                manager.store().get("2").unwrap().unwrap().access_token,
+                "TOKEN_2"
+            );
+            assert_eq!(rets[2].0, "3");
+            assert!(matches!(rets[2].1, Ok(TokenRefreshOutcome::Refreshed(_))));
+            assert_eq!(
+                manager.store().get("3").unwrap().unwrap().access_token,
+                "x*144"
+            );
+        })
+    }
+
+    //
+    #[derive(Debug, Default)]
+    struct PutManyFailingTokenStore {
+        inner: MemoryTokenStore,
+        put_many_calls: Mutex<usize>,
+    }
+
+    impl TokenStore for PutManyFailingTokenStore {
+        type Error = IoError;
+
+        fn get(&self, key: &str) -> Result<Option<ManagedToken>, Self::Error> {
+            Ok(self.inner.get(key).unwrap())
+        }
+
+        fn put(&self, key: &str, token: &ManagedToken) -> Result<(), Self::Error> {
+            self.inner.put(key, token).unwrap();
+            Ok(())
+        }
+
+        // The first chunk fails
+        fn put_many(&self, tokens: &[(String, ManagedToken)]) -> Result<(), Self::Error> {
+            let mut calls = self.put_many_calls.lock().unwrap();
+            *calls += 1;
+            if *calls == 1 {
+                return Err(IoError::other("disk full"));
+            }
+            self.inner.put_many(tokens).unwrap();
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> Result<(), Self::Error> {
+            self.inner.remove(key).unwrap();
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, Self::Error> {
+            Ok(self.inner.keys().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_refresh_all_due_with_put_failed() {
+        futures_lite::future::block_on(async {
+            let ok_json =
+                include_str!("../../tests/response_body_files/refresh_access_token_ok.json");
+            let client = MockClient {
+                responses: Mutex::new(vec![(StatusCode::OK, ok_json); 3].into()),
+                ..Default::default()
+            };
+            let manager =
+                TokenManager::new(PutManyFailingTokenStore::default()).with_put_chunk_size(2);
+            manager.insert("1", &token("TOKEN_1", 55)).unwrap();
+            manager.insert("2", &token("TOKEN_2", 55)).unwrap();
+            manager.insert("3", &token("TOKEN_3", 55)).unwrap();
+
+            let output = manager.refresh_all_due(&client).await.unwrap();
+            assert_eq!(output.rets.len(), 3);
+            assert!(output
+                .rets
+                .iter()
+                .all(|(_, ret)| matches!(ret, Ok(TokenRefreshOutcome::Refreshed(_)))));
+            assert_eq!(output.put_failures.len(), 1);
+            assert_eq!(output.put_failures[0].0, vec!["1", "2"]);
+
+            // The failed chunk is kept as is, the next one is put
+            assert_eq!(
+                manager.store().get("1").unwrap().unwrap().access_token,
+                "TOKEN_1"
+            );
+            assert_eq!(
+                manager.store().get("3").unwrap().unwrap().access_token,
+                "x*144"
+            );
+        })
+    }
+}
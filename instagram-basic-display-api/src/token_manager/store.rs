@@ -0,0 +1,290 @@
+use core::convert::Infallible;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Error as IoError, ErrorKind, Write as _},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use serde_json::Error as SerdeJsonError;
+
+use super::ManagedToken;
+
+// Methods are sync and called from the async refresh path of TokenManager,
+// a store doing blocking I/O (e.g. JsonFileTokenStore) blocks the executor while it runs
+pub trait TokenStore {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn get(&self, key: &str) -> Result<Option<ManagedToken>, Self::Error>;
+    fn put(&self, key: &str, token: &ManagedToken) -> Result<(), Self::Error>;
+    fn put_many(&self, tokens: &[(String, ManagedToken)]) -> Result<(), Self::Error> {
+        for (key, token) in tokens {
+            self.put(key, token)?;
+        }
+        Ok(())
+    }
+    fn remove(&self, key: &str) -> Result<(), Self::Error>;
+    fn keys(&self) -> Result<Vec<String>, Self::Error>;
+}
+
+//
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<BTreeMap<String, ManagedToken>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    type Error = Infallible;
+
+    fn get(&self, key: &str) -> Result<Option<ManagedToken>, Self::Error> {
+        Ok(self.tokens.lock().expect("Never").get(key).cloned())
+    }
+
+    fn put(&self, key: &str, token: &ManagedToken) -> Result<(), Self::Error> {
+        self.tokens
+            .lock()
+            .expect("Never")
+            .insert(key.into(), token.to_owned());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        self.tokens.lock().expect("Never").remove(key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, Self::Error> {
+        Ok(self.tokens.lock().expect("Never").keys().cloned().collect())
+    }
+}
+
+// All tokens are kept in memory, every write rewrites the whole file,
+// so a put costs O(n), prefer put_many for batches.
+// Handles a few thousand tokens, TokenManager::refresh_all_due puts them in chunks.
+// For larger sets or several processes, implement TokenStore on a database.
+#[derive(Debug)]
+pub struct JsonFileTokenStore {
+    path: PathBuf,
+    tokens: Mutex<BTreeMap<String, ManagedToken>>,
+}
+
+impl JsonFileTokenStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JsonFileTokenStoreError> {
+        let path = path.as_ref().to_path_buf();
+        let tokens = if path.exists() {
+            let bytes = fs::read(&path).map_err(JsonFileTokenStoreError::ReadFailed)?;
+            serde_json::from_slice(&bytes).map_err(JsonFileTokenStoreError::DeFailed)?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            path,
+            tokens: Mutex::new(tokens),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write(
+        &self,
+        tokens: &BTreeMap<String, ManagedToken>,
+    ) -> Result<(), JsonFileTokenStoreError> {
+        let bytes =
+            serde_json::to_vec_pretty(tokens).map_err(JsonFileTokenStoreError::SerFailed)?;
+
+        // Write, sync, rename then sync the directory, a crash never leaves a truncated file
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        // Left by a crashed write, it may have been created with other permissions
+        match fs::remove_file(&tmp_path) {
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(JsonFileTokenStoreError::WriteFailed(err)),
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        // Tokens are secrets, only readable by the owner
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options
+            .open(&tmp_path)
+            .map_err(JsonFileTokenStoreError::WriteFailed)?;
+        file.write_all(&bytes)
+            .map_err(JsonFileTokenStoreError::WriteFailed)?;
+        file.sync_all()
+            .map_err(JsonFileTokenStoreError::WriteFailed)?;
+        drop(file);
+
+        fs::rename(&tmp_path, &self.path).map_err(JsonFileTokenStoreError::WriteFailed)?;
+
+        // Persist the rename itself, directories can not be opened for sync on windows
+        #[cfg(unix)]
+        {
+            let dir = match self.path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            fs::File::open(dir)
+                .and_then(|dir| dir.sync_all())
+                .map_err(JsonFileTokenStoreError::WriteFailed)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TokenStore for JsonFileTokenStore {
+    type Error = JsonFileTokenStoreError;
+
+    fn get(&self, key: &str) -> Result<Option<ManagedToken>, Self::Error> {
+        Ok(self.tokens.lock().expect("Never").get(key).cloned())
+    }
+
+    fn put(&self, key: &str, token: &ManagedToken) -> Result<(), Self::Error> {
+        let mut tokens = self.tokens.lock().expect("Never");
+        let mut tokens_new = tokens.clone();
+        tokens_new.insert(key.into(), token.to_owned());
+        self.write(&tokens_new)?;
+        *tokens = tokens_new;
+        Ok(())
+    }
+
+    fn put_many(&self, tokens_put: &[(String, ManagedToken)]) -> Result<(), Self::Error> {
+        if tokens_put.is_empty() {
+            return Ok(());
+        }
+        let mut tokens = self.tokens.lock().expect("Never");
+        let mut tokens_new = tokens.clone();
+        for (key, token) in tokens_put {
+            tokens_new.insert(key.to_owned(), token.to_owned());
+        }
+        self.write(&tokens_new)?;
+        *tokens = tokens_new;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        let mut tokens = self.tokens.lock().expect("Never");
+        if !tokens.contains_key(key) {
+            return Ok(());
+        }
+        let mut tokens_new = tokens.clone();
+        tokens_new.remove(key);
+        self.write(&tokens_new)?;
+        *tokens = tokens_new;
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, Self::Error> {
+        Ok(self.tokens.lock().expect("Never").keys().cloned().collect())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum JsonFileTokenStoreError {
+    #[error("ReadFailed {0}")]
+    ReadFailed(IoError),
+    #[error("WriteFailed {0}")]
+    WriteFailed(IoError),
+    #[error("DeFailed {0}")]
+    DeFailed(SerdeJsonError),
+    #[error("SerFailed {0}")]
+    SerFailed(SerdeJsonError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Utc};
+
+    fn token(access_token: &str) -> ManagedToken {
+        let issued_at = DateTime::parse_from_rfc3339("2022-10-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        ManagedToken::new(access_token, issued_at, None)
+    }
+
+    #[test]
+    fn test_memory_token_store() {
+        let store = MemoryTokenStore::new();
+        store.put("1", &token("TOKEN_1")).unwrap();
+        store.put("2", &token("TOKEN_2")).unwrap();
+        assert_eq!(store.get("1").unwrap(), Some(token("TOKEN_1")));
+        assert_eq!(store.keys().unwrap(), vec!["1", "2"]);
+
+        store.remove("1").unwrap();
+        assert_eq!(store.get("1").unwrap(), None);
+        assert_eq!(store.keys().unwrap(), vec!["2"]);
+    }
+
+    #[test]
+    fn test_json_file_token_store() {
+        let path = std::env::temp_dir().join(format!(
+            "instagram_basic_display_api_token_store_{}.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let _ = fs::remove_file(&tmp_path);
+
+        let store = JsonFileTokenStore::open(&path).unwrap();
+        assert!(store.keys().unwrap().is_empty());
+
+        // A world readable tmp file left by a crashed write
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            fs::write(&tmp_path, "{").unwrap();
+            fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o644)).unwrap();
+        }
+        store.put("1", &token("TOKEN_1")).unwrap();
+        store.put("2", &token("TOKEN_2")).unwrap();
+        store.remove("2").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            assert_eq!(
+                fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+                0o600
+            );
+        }
+
+        let store = JsonFileTokenStore::open(&path).unwrap();
+        assert_eq!(store.keys().unwrap(), vec!["1"]);
+        assert_eq!(store.get("1").unwrap(), Some(token("TOKEN_1")));
+
+        store
+            .put_many(&[
+                ("1".into(), token("TOKEN_1_NEW")),
+                ("3".into(), token("TOKEN_3")),
+            ])
+            .unwrap();
+        let store = JsonFileTokenStore::open(&path).unwrap();
+        assert_eq!(store.keys().unwrap(), vec!["1", "3"]);
+        assert_eq!(store.get("1").unwrap(), Some(token("TOKEN_1_NEW")));
+
+        fs::write(&path, "{").unwrap();
+        assert!(matches!(
+            JsonFileTokenStore::open(&path),
+            Err(JsonFileTokenStoreError::DeFailed(_))
+        ));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+use core::time::Duration;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    operations::{
+        ExchangeSlAccessTokenForLlAccessTokenResponseBody, RefreshAccessTokenResponseBody,
+    },
+    types::{
+        access_token::{
+            LONG_LIVED_USER_ACCESS_TOKEN_LIFETIME, LONG_LIVED_USER_ACCESS_TOKEN_REFRESHABLE_AFTER,
+        },
+        LongLivedUserAccessToken,
+    },
+};
+
+//
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManagedToken {
+    pub access_token: String,
+    pub issued_at: DateTime<Utc>,
+    // Seconds
+    pub expires_in: u32,
+}
+
+impl ManagedToken {
+    pub fn new(
+        access_token: impl Into<LongLivedUserAccessToken>,
+        issued_at: DateTime<Utc>,
+        expires_in: Option<u32>,
+    ) -> Self {
+        Self {
+            access_token: access_token.into().into_inner(),
+            issued_at,
+            expires_in: expires_in
+                .unwrap_or(LONG_LIVED_USER_ACCESS_TOKEN_LIFETIME.as_secs() as u32),
+        }
+    }
+
+    pub fn from_exchange_response_body(
+        body: &ExchangeSlAccessTokenForLlAccessTokenResponseBody,
+        issued_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(&body.access_token, issued_at, body.expires_in)
+    }
+
+    pub fn from_refresh_response_body(
+        body: &RefreshAccessTokenResponseBody,
+        issued_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(&body.access_token, issued_at, body.expires_in)
+    }
+
+    pub fn access_token(&self) -> LongLivedUserAccessToken {
+        LongLivedUserAccessToken::from(&self.access_token)
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.issued_at + ChronoDuration::seconds(self.expires_in as i64)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at()
+    }
+
+    pub fn is_refreshable(&self, now: DateTime<Utc>) -> bool {
+        !self.is_expired(now)
+            && now - self.issued_at >= to_chrono(LONG_LIVED_USER_ACCESS_TOKEN_REFRESHABLE_AFTER)
+    }
+
+    // Refreshable and expiring within refresh_before
+    pub fn is_refresh_due(&self, now: DateTime<Utc>, refresh_before: Duration) -> bool {
+        self.is_refreshable(now) && self.expires_at() - now <= to_chrono(refresh_before)
+    }
+}
+
+fn to_chrono(dur: Duration) -> ChronoDuration {
+    ChronoDuration::seconds(dur.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(n: i64) -> ChronoDuration {
+        ChronoDuration::days(n)
+    }
+
+    #[test]
+    fn test_is_refresh_due() {
+        let issued_at = DateTime::parse_from_rfc3339("2022-10-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let token = ManagedToken::new("TOKEN", issued_at, None);
+        assert_eq!(token.expires_in, 5184000);
+        assert_eq!(token.expires_at(), issued_at + days(60));
+
+        let refresh_before = Duration::from_secs(3600 * 24 * 7);
+
+        // Less than 24 hours old
+        assert!(!token.is_refreshable(issued_at + ChronoDuration::hours(23)));
+        assert!(token.is_refreshable(issued_at + days(1)));
+        assert!(!token.is_refresh_due(issued_at + days(1), refresh_before));
+
+        assert!(!token.is_refresh_due(issued_at + days(52), refresh_before));
+        assert!(token.is_refresh_due(issued_at + days(53), refresh_before));
+
+        assert!(!token.is_expired(issued_at + days(60) - ChronoDuration::seconds(1)));
+        assert!(token.is_expired(issued_at + days(60)));
+        assert!(!token.is_refresh_due(issued_at + days(60), refresh_before));
+    }
+
+    #[test]
+    fn test_from_response_body() {
+        let issued_at = DateTime::parse_from_rfc3339("2022-10-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let body = serde_json::from_str::<ExchangeSlAccessTokenForLlAccessTokenResponseBody>(
+            include_str!(
+                "../../tests/response_body_files/exchange_sl_access_token_for_ll_access_token_ok.json"
+            ),
+        )
+        .unwrap();
+        let token = ManagedToken::from_exchange_response_body(&body, issued_at);
+        assert_eq!(token.access_token, body.access_token);
+        assert_eq!(token.expires_in, 5184000);
+
+        let body = serde_json::from_str::<RefreshAccessTokenResponseBody>(include_str!(
+            "../../tests/response_body_files/refresh_access_token_ok.json"
+        ))
+        .unwrap();
+        let token = ManagedToken::from_refresh_response_body(&body, issued_at);
+        assert_eq!(token.expires_in, 5183828);
+    }
+}
@@ -7,6 +7,8 @@ use core::time::Duration;
 //
 pub const LONG_LIVED_USER_ACCESS_TOKEN_LIFETIME: Duration = Duration::from_secs(3600 * 24 * 60);
 pub const SHORT_LIVED_USER_ACCESS_TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+// Long-lived tokens can only be refreshed once they are at least 24 hours old
+pub const LONG_LIVED_USER_ACCESS_TOKEN_REFRESHABLE_AFTER: Duration = Duration::from_secs(3600 * 24);
 
 wrapping_macro::wrapping_string! {
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,3 +1,6 @@
 //
 pub mod objects;
 pub mod operations;
+pub mod types;
+pub mod webhooks;
+pub mod workflows;
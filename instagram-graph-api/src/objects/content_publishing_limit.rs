@@ -0,0 +1,34 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-api/reference/ig-user/content_publishing_limit)
+
+use serde::{Deserialize, Serialize};
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ContentPublishingLimit {
+    pub config: Option<ContentPublishingLimitConfig>,
+    // Number of times published within the quota_duration
+    pub quota_usage: u32,
+}
+
+impl ContentPublishingLimit {
+    pub fn fields() -> Box<str> {
+        "config,quota_usage".into()
+    }
+
+    pub fn remaining(&self) -> Option<u32> {
+        self.config
+            .as_ref()
+            .map(|config| config.quota_total.saturating_sub(self.quota_usage))
+    }
+
+    pub fn is_reached(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ContentPublishingLimitConfig {
+    pub quota_total: u32,
+    // In seconds
+    pub quota_duration: u64,
+}
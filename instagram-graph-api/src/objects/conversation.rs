@@ -0,0 +1,52 @@
+//! [Ref](https://developers.facebook.com/docs/messenger-platform/instagram/features/conversation)
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConversationForPageConversationsReadingOperation {
+    pub id: Box<str>,
+    pub updated_time: DateTime<Utc>,
+    pub participants: Option<ConversationParticipants>,
+}
+
+impl ConversationForPageConversationsReadingOperation {
+    pub fn fields() -> Box<str> {
+        "id,updated_time,participants".into()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ConversationParticipants {
+    pub data: Vec<ConversationParticipant>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConversationParticipant {
+    // IGSID, or IG User id of the business account itself
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub username: Option<String>,
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConversationMessage {
+    pub id: Box<str>,
+    pub created_time: DateTime<Utc>,
+    pub from: ConversationParticipant,
+    pub to: ConversationParticipants,
+    // Empty if the message only contains attachments
+    #[serde(default)]
+    pub message: String,
+}
+
+impl ConversationMessage {
+    pub fn fields() -> Box<str> {
+        "id,created_time,from,to,message".into()
+    }
+}
@@ -0,0 +1,89 @@
+//! [Ref](https://developers.facebook.com/docs/graph-api/reference/debug_token)
+
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_option_number_from_string;
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DebugToken {
+    // Not set for malformed input tokens
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub app_id: Option<u64>,
+    pub r#type: Option<DebugTokenType>,
+    pub application: Option<String>,
+    // Unix timestamps, 0 for never
+    #[serde(default)]
+    pub data_access_expires_at: u64,
+    #[serde(default)]
+    pub expires_at: u64,
+    pub issued_at: Option<u64>,
+    pub is_valid: bool,
+    #[serde(default)]
+    pub scopes: Vec<Box<str>>,
+    #[serde(default)]
+    pub granular_scopes: Vec<DebugTokenGranularScope>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub user_id: Option<u64>,
+    // Set for page tokens
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub profile_id: Option<u64>,
+    pub error: Option<DebugTokenError>,
+}
+
+impl DebugToken {
+    pub fn has_scope(&self, scope: impl AsRef<str>) -> bool {
+        self.scopes.iter().any(|x| x.as_ref() == scope.as_ref())
+    }
+
+    // A scope without target_ids applies to every target
+    pub fn has_scope_for_target(&self, scope: impl AsRef<str>, target_id: u64) -> bool {
+        if !self.has_scope(&scope) {
+            return false;
+        }
+        match self
+            .granular_scopes
+            .iter()
+            .find(|x| x.scope.as_ref() == scope.as_ref())
+        {
+            Some(granular_scope) if !granular_scope.target_ids.is_empty() => granular_scope
+                .target_ids
+                .iter()
+                .any(|x| x.as_ref() == target_id.to_string()),
+            _ => true,
+        }
+    }
+
+    pub fn missing_scopes<'a>(&self, scopes: &[&'a str]) -> Vec<&'a str> {
+        scopes
+            .iter()
+            .filter(|x| !self.has_scope(x))
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DebugTokenType {
+    User,
+    Page,
+    App,
+    SystemUser,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DebugTokenGranularScope {
+    pub scope: Box<str>,
+    #[serde(default)]
+    pub target_ids: Vec<Box<str>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DebugTokenError {
+    pub code: i64,
+    pub message: String,
+    pub subcode: Option<i64>,
+}
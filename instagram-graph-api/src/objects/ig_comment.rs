@@ -10,9 +10,12 @@ use serde_aux::field_attributes::{
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct IgCommentForIgMediaCommentsReadingOperation {
     pub from: Option<IgCommentFrom>,
+    // hidden and like_count are not returned for comments on live media
+    #[serde(default)]
     pub hidden: bool,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub id: u64,
+    #[serde(default)]
     pub like_count: u32,
     #[serde(default)]
     pub text: Box<str>,
@@ -77,6 +80,25 @@ impl IgCommentForIgCommentReadingOperation {
     }
 }
 
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgCommentForIgUserMentionedCommentReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    #[serde(default)]
+    pub like_count: u32,
+    pub media: IgCommentMedia,
+    #[serde(default)]
+    pub text: Box<str>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl IgCommentForIgUserMentionedCommentReadingOperation {
+    pub fn fields() -> Box<str> {
+        "id,like_count,media,text,timestamp".into()
+    }
+}
+
 //
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct IgCommentFrom {
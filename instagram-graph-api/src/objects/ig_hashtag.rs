@@ -0,0 +1,14 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-api/reference/ig-hashtag#fields)
+
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgHashtag {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // Only id is returned by ig_hashtag_search and recently_searched_hashtags
+    #[serde(default)]
+    pub name: Option<Box<str>>,
+}
@@ -1,8 +1,83 @@
 //! [Ref](https://developers.facebook.com/docs/instagram-api/reference/ig-media#fields)
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{
+    deserialize_number_from_string, deserialize_option_number_from_string,
+};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMedia {
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // Deprecated, may be omitted
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub ig_id: Option<u64>,
+    pub is_comment_enabled: Option<bool>,
+    // Only available on REELS media
+    pub is_shared_to_feed: Option<bool>,
+    #[serde(default)]
+    pub like_count: u32,
+    pub media_product_type: IgMediaProductType,
+    pub media_type: IgMediaType,
+    // Omitted from responses if the media contains copyrighted material
+    pub media_url: Option<String>,
+    pub owner: Option<IgMediaOwner>,
+    pub permalink: String,
+    pub shortcode: Box<str>,
+    // Only available on VIDEO media
+    pub thumbnail_url: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub username: String,
+    //
+    pub children: Option<IgMediaChildren>,
+}
+
+impl IgMedia {
+    pub fn fields() -> Box<str> {
+        format!(
+            "caption,comments_count,id,ig_id,is_comment_enabled,is_shared_to_feed,like_count,media_product_type,media_type,media_url,owner,permalink,shortcode,thumbnail_url,timestamp,username,children{{{}}}",
+            IgMediaChild::fields()
+        )
+        .into()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaOwner {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct IgMediaChildren {
+    pub data: Vec<IgMediaChild>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaChild {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub media_type: IgMediaType,
+    pub media_url: Option<String>,
+    pub permalink: String,
+    pub shortcode: Box<str>,
+    pub thumbnail_url: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl IgMediaChild {
+    pub fn fields() -> Box<str> {
+        "id,media_type,media_url,permalink,shortcode,thumbnail_url,timestamp".into()
+    }
+}
 
 //
 //
@@ -46,6 +121,154 @@ pub struct IgMediaChildForIgUserBusinessDiscoveryReadingOperation {
     pub timestamp: DateTime<Utc>,
 }
 
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaForIgHashtagMediaReadingOperation {
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // Omitted from responses if the media owner has hidden like counts
+    #[serde(default)]
+    pub like_count: u32,
+    pub media_type: IgMediaType,
+    pub media_url: Option<String>,
+    pub permalink: String,
+    pub timestamp: DateTime<Utc>,
+    //
+    pub children: Option<IgMediaChildrenForIgHashtagMediaReadingOperation>,
+}
+
+impl IgMediaForIgHashtagMediaReadingOperation {
+    pub fn fields() -> Box<str> {
+        "caption,comments_count,id,like_count,media_type,media_url,permalink,timestamp,children{id,media_type,media_url,permalink}".into()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaChildrenForIgHashtagMediaReadingOperation {
+    pub data: Vec<IgMediaChildForIgHashtagMediaReadingOperation>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaChildForIgHashtagMediaReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub media_type: IgMediaType,
+    pub media_url: Option<String>,
+    pub permalink: String,
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaForIgUserMentionedMediaReadingOperation {
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    #[serde(default)]
+    pub like_count: u32,
+    pub media_type: IgMediaType,
+    pub media_url: Option<String>,
+    pub owner: Option<IgMediaOwner>,
+    pub timestamp: DateTime<Utc>,
+    pub username: String,
+}
+
+impl IgMediaForIgUserMentionedMediaReadingOperation {
+    pub fn fields() -> Box<str> {
+        "caption,comments_count,id,like_count,media_type,media_url,owner,timestamp,username".into()
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaForIgUserTagsReadingOperation {
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    #[serde(default)]
+    pub like_count: u32,
+    pub media_product_type: Option<IgMediaProductType>,
+    pub media_type: IgMediaType,
+    pub media_url: Option<String>,
+    pub owner: Option<IgMediaOwner>,
+    pub permalink: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    // Username of the tagging account
+    pub username: String,
+}
+
+impl IgMediaForIgUserTagsReadingOperation {
+    pub fn fields() -> Box<str> {
+        "caption,comments_count,id,like_count,media_product_type,media_type,media_url,owner,permalink,thumbnail_url,timestamp,username".into()
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaForIgUserStoriesReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub media_product_type: Option<IgMediaProductType>,
+    pub media_type: IgMediaType,
+    // Not returned if the story contains copyrighted material
+    pub media_url: Option<String>,
+    pub permalink: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub username: Option<String>,
+}
+
+impl IgMediaForIgUserStoriesReadingOperation {
+    pub fn fields() -> Box<str> {
+        "id,media_product_type,media_type,media_url,permalink,thumbnail_url,timestamp,username"
+            .into()
+    }
+
+    // Stories are only available for 24 hours after being published
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.timestamp + Duration::hours(24)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at()
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaForIgUserLiveMediaReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub media_product_type: Option<IgMediaProductType>,
+    pub media_type: IgMediaType,
+    pub owner: Option<IgMediaOwner>,
+    pub timestamp: DateTime<Utc>,
+    pub username: Option<String>,
+}
+
+impl IgMediaForIgUserLiveMediaReadingOperation {
+    pub fn fields() -> Box<str> {
+        "id,media_product_type,media_type,owner,timestamp,username".into()
+    }
+}
+
 //
 //
 //
@@ -65,10 +288,31 @@ impl IgMediaForIgUserMediaPublishCreatingOperation {
 //
 //
 #[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgMediaCollaborator {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub username: String,
+    pub invite_status: IgMediaCollaboratorInviteStatus,
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+pub enum IgMediaCollaboratorInviteStatus {
+    Accepted,
+    Pending,
+    Declined,
+    #[serde(other)]
+    Other(String),
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub enum IgMediaProductType {
     #[serde(rename = "AD")]
     Ad,
     #[serde(rename = "FEED")]
+    #[default]
     Feed,
     #[serde(rename = "STORY")]
     Story,
@@ -76,26 +320,20 @@ pub enum IgMediaProductType {
     Igtv,
     #[serde(rename = "REELS")]
     Reels,
+    #[serde(rename = "LIVE")]
+    Live,
 }
 
-impl Default for IgMediaProductType {
-    fn default() -> Self {
-        Self::Feed
-    }
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub enum IgMediaType {
     #[serde(rename = "IMAGE")]
+    #[default]
     Photo,
     #[serde(rename = "VIDEO")]
     Video,
     #[serde(rename = "CAROUSEL_ALBUM")]
     Album,
-}
-
-impl Default for IgMediaType {
-    fn default() -> Self {
-        Self::Photo
-    }
+    // Only on live media
+    #[serde(rename = "BROADCAST")]
+    Broadcast,
 }
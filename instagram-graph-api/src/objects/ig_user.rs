@@ -7,6 +7,36 @@ use serde_aux::field_attributes::deserialize_number_from_string;
 
 use crate::objects::IgMediaForIgUserBusinessDiscoveryReadingOperation;
 
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IgUser {
+    pub biography: Option<String>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ig_id: u64,
+    #[serde(default)]
+    pub followers_count: u32,
+    #[serde(default)]
+    pub follows_count: u32,
+    #[serde(default)]
+    pub media_count: u32,
+    pub name: Option<String>,
+    pub profile_picture_url: Option<String>,
+    // Requires the catalog_management permission
+    pub shopping_product_tag_eligibility: Option<bool>,
+    pub username: String,
+    pub website: Option<String>,
+}
+
+impl IgUser {
+    pub fn fields() -> Box<str> {
+        "biography,id,ig_id,followers_count,follows_count,media_count,name,profile_picture_url,shopping_product_tag_eligibility,username,website".into()
+    }
+}
+
 //
 //
 //
@@ -0,0 +1,190 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-api/reference/ig-user/insights)
+//! [Ref](https://developers.facebook.com/docs/instagram-api/reference/ig-media/insights)
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Insights<M> {
+    pub id: Box<str>,
+    pub name: M,
+    pub period: InsightsPeriod,
+    pub title: Option<Box<str>>,
+    pub description: Option<Box<str>>,
+    #[serde(default)]
+    pub values: Vec<InsightsValue>,
+    pub total_value: Option<InsightsTotalValue>,
+}
+
+pub type IgUserInsights = Insights<IgUserInsightsMetric>;
+pub type IgMediaInsights = Insights<IgMediaInsightsMetric>;
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InsightsValue {
+    pub value: InsightsValueValue,
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InsightsValueValue {
+    Number(u64),
+    // e.g. audience_city, audience_gender_age, online_followers
+    Map(HashMap<Box<str>, u64>),
+}
+
+impl InsightsValueValue {
+    pub fn as_number(&self) -> Option<u64> {
+        match self {
+            Self::Number(x) => Some(*x),
+            Self::Map(_) => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&HashMap<Box<str>, u64>> {
+        match self {
+            Self::Number(_) => None,
+            Self::Map(x) => Some(x),
+        }
+    }
+}
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InsightsTotalValue {
+    pub value: u64,
+    #[serde(default)]
+    pub breakdowns: Vec<InsightsTotalValueBreakdown>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InsightsTotalValueBreakdown {
+    pub dimension_keys: Vec<InsightsBreakdown>,
+    pub results: Vec<InsightsTotalValueBreakdownResult>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InsightsTotalValueBreakdownResult {
+    pub dimension_values: Vec<Box<str>>,
+    pub value: u64,
+}
+
+//
+// Time-based, no cursors.
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InsightsPaging {
+    pub previous: Option<String>,
+    pub next: Option<String>,
+}
+
+//
+//
+//
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IgUserInsightsMetric {
+    Impressions,
+    Reach,
+    FollowerCount,
+    EmailContacts,
+    PhoneCallClicks,
+    TextMessageClicks,
+    GetDirectionsClicks,
+    WebsiteClicks,
+    ProfileViews,
+    OnlineFollowers,
+    AudienceCity,
+    AudienceCountry,
+    AudienceGenderAge,
+    AudienceLocale,
+    AccountsEngaged,
+    TotalInteractions,
+    Likes,
+    Comments,
+    Shares,
+    Saves,
+    Replies,
+    FollowsAndUnfollows,
+    ProfileLinksTaps,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IgMediaInsightsMetric {
+    Impressions,
+    Reach,
+    Engagement,
+    Saved,
+    VideoViews,
+    Plays,
+    TotalInteractions,
+    Likes,
+    Comments,
+    Shares,
+    Follows,
+    ProfileVisits,
+    ProfileActivity,
+    // STORY
+    Exits,
+    Replies,
+    TapsForward,
+    TapsBack,
+    Navigation,
+    // REELS
+    IgReelsAvgWatchTime,
+    IgReelsVideoViewTotalTime,
+    // CAROUSEL_ALBUM
+    CarouselAlbumEngagement,
+    CarouselAlbumImpressions,
+    CarouselAlbumReach,
+    CarouselAlbumSaved,
+    CarouselAlbumVideoViews,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsightsPeriod {
+    Day,
+    Week,
+    #[serde(rename = "days_28")]
+    Days28,
+    Month,
+    Lifetime,
+    TotalOverRange,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsightsMetricType {
+    Default,
+    TimeSeries,
+    TotalValue,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsightsBreakdown {
+    ActionType,
+    ContactButtonType,
+    FollowType,
+    MediaProductType,
+    StoryNavigationActionType,
+    #[serde(other)]
+    Other(String),
+}
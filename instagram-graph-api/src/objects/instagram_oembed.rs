@@ -0,0 +1,20 @@
+//! [Ref](https://developers.facebook.com/docs/instagram/oembed#fields)
+
+use serde::{Deserialize, Serialize};
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InstagramOembed {
+    pub version: Option<Box<str>>,
+    pub r#type: Option<Box<str>>,
+    pub author_name: Option<String>,
+    pub provider_name: String,
+    pub provider_url: Option<String>,
+    pub html: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    // Not returned for media without a thumbnail, e.g. when the media is removed
+    pub thumbnail_url: Option<String>,
+    pub thumbnail_width: Option<u32>,
+    pub thumbnail_height: Option<u32>,
+}
@@ -4,18 +4,46 @@ pub mod err_json;
 pub use err_json::ErrJson as ResponseBodyErrJson;
 
 //
+pub mod content_publishing_limit;
+pub mod conversation;
+pub mod debug_token;
 pub mod ig_comment;
 pub mod ig_container;
+pub mod ig_hashtag;
 pub mod ig_media;
 pub mod ig_user;
+pub mod insights;
+pub mod instagram_oembed;
+pub mod page;
+pub mod product;
 
+pub use content_publishing_limit::{ContentPublishingLimit, ContentPublishingLimitConfig};
+pub use conversation::{
+    ConversationForPageConversationsReadingOperation, ConversationMessage, ConversationParticipant,
+    ConversationParticipants,
+};
+pub use debug_token::{DebugToken, DebugTokenError, DebugTokenGranularScope, DebugTokenType};
 pub use ig_comment::{
     IgCommentAsReply, IgCommentForIgCommentReadingOperation,
     IgCommentForIgMediaCommentsCreatingOperation, IgCommentForIgMediaCommentsReadingOperation,
+    IgCommentForIgUserMentionedCommentReadingOperation,
 };
 pub use ig_container::{IgContainer, IgContainerStatusCode};
+pub use ig_hashtag::IgHashtag;
 pub use ig_media::{
-    IgMediaForIgUserBusinessDiscoveryReadingOperation,
-    IgMediaForIgUserMediaPublishCreatingOperation,
+    IgMedia, IgMediaCollaborator, IgMediaCollaboratorInviteStatus,
+    IgMediaForIgHashtagMediaReadingOperation, IgMediaForIgUserBusinessDiscoveryReadingOperation,
+    IgMediaForIgUserLiveMediaReadingOperation, IgMediaForIgUserMediaPublishCreatingOperation,
+    IgMediaForIgUserMentionedMediaReadingOperation, IgMediaForIgUserStoriesReadingOperation,
+    IgMediaForIgUserTagsReadingOperation,
+};
+pub use ig_user::{IgUser, IgUserForIgUserBusinessDiscoveryReadingOperation};
+pub use insights::{IgMediaInsights, IgUserInsights, Insights};
+pub use instagram_oembed::InstagramOembed;
+pub use page::{
+    PageForMeAccountsReadingOperation, PageForPageReadingOperation, PageInstagramAccount,
+};
+pub use product::{
+    AvailableCatalog, CatalogProduct, CatalogProductVariant, ProductAppeal, ProductReviewStatus,
+    ProductTag,
 };
-pub use ig_user::IgUserForIgUserBusinessDiscoveryReadingOperation;
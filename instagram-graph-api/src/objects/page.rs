@@ -0,0 +1,53 @@
+//! [Ref](https://developers.facebook.com/docs/graph-api/reference/page#fields)
+
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+
+use crate::types::PageAccessToken;
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PageForMeAccountsReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub name: Option<String>,
+    // Page access token
+    pub access_token: Option<Box<str>>,
+    pub category: Option<String>,
+    #[serde(default)]
+    pub tasks: Vec<Box<str>>,
+    pub instagram_business_account: Option<PageInstagramAccount>,
+}
+
+impl PageForMeAccountsReadingOperation {
+    pub fn fields() -> Box<str> {
+        "id,name,access_token,category,tasks,instagram_business_account".into()
+    }
+
+    pub fn page_access_token(&self) -> Option<PageAccessToken> {
+        self.access_token.as_ref().map(PageAccessToken::from)
+    }
+}
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PageForPageReadingOperation {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // IG User id, the one every ig_user operation takes
+    pub instagram_business_account: Option<PageInstagramAccount>,
+    pub connected_instagram_account: Option<PageInstagramAccount>,
+}
+
+impl PageForPageReadingOperation {
+    pub fn fields() -> Box<str> {
+        "instagram_business_account,connected_instagram_account".into()
+    }
+}
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PageInstagramAccount {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+}
@@ -0,0 +1,100 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-api/guides/product-tagging)
+
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::{
+    deserialize_number_from_string, deserialize_option_number_from_string,
+};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AvailableCatalog {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub catalog_id: u64,
+    pub catalog_name: String,
+    pub shop_name: Option<String>,
+    #[serde(default)]
+    pub product_count: u32,
+}
+
+impl AvailableCatalog {
+    pub fn fields() -> Box<str> {
+        "catalog_id,catalog_name,shop_name,product_count".into()
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CatalogProduct {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub product_id: u64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub merchant_id: Option<u64>,
+    pub product_name: String,
+    pub image_url: Option<String>,
+    pub retailer_id: Option<String>,
+    pub review_status: Option<ProductReviewStatus>,
+    #[serde(default)]
+    pub is_checkout_flow: bool,
+    #[serde(default)]
+    pub product_variants: Vec<CatalogProductVariant>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CatalogProductVariant {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub product_id: u64,
+    pub variant_name: Option<String>,
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProductTag {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub product_id: u64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub merchant_id: Option<u64>,
+    pub name: String,
+    pub price_string: Option<String>,
+    pub image_url: Option<String>,
+    pub review_status: Option<ProductReviewStatus>,
+    #[serde(default)]
+    pub is_checkout: bool,
+    pub stripped_price_string: Option<String>,
+    pub stripped_sale_price_string: Option<String>,
+    // Not returned for product tags on videos
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProductAppeal {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub product_id: u64,
+    pub eligible_for_appeal: bool,
+    pub review_status: Option<ProductReviewStatus>,
+}
+
+//
+//
+//
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductReviewStatus {
+    Approved,
+    Rejected,
+    Pending,
+    Outdated,
+    NoReview,
+    #[serde(other)]
+    Other(String),
+}
@@ -0,0 +1,196 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::{DebugToken, ResponseBodyErrJson},
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::DebugTokenAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub input_token: Box<str>,
+    //
+    // App access token, or a user access token of an app developer
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        input_token: impl AsRef<str>,
+        access_token: impl DebugTokenAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            input_token: input_token.as_ref().into(),
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/debug_token",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("input_token", &self.input_token)
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    pub data: DebugToken,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        objects::DebugTokenType,
+        types::{AppAccessToken, UserAccessToken},
+    };
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content =
+            include_str!("../../../tests/response_body_json_files/v14.0/debug_token__reading.json");
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                let data = ok_json.data;
+                assert_eq!(data.app_id, Some(138483919580948));
+                assert_eq!(data.r#type, Some(DebugTokenType::User));
+                assert!(data.is_valid);
+                assert_eq!(data.expires_at, 1352419328);
+                assert_eq!(data.data_access_expires_at, 1576687825);
+                assert_eq!(data.user_id, Some(1207059));
+                assert!(data.has_scope("instagram_basic"));
+                assert!(data.has_scope_for_target("instagram_basic", 17841406427775093));
+                assert!(!data.has_scope_for_target("instagram_basic", 1));
+                assert!(data.has_scope_for_target("pages_show_list", 1));
+                assert_eq!(
+                    data.missing_scopes(&["instagram_basic", "instagram_content_publish"]),
+                    vec!["instagram_content_publish"]
+                );
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/debug_token__reading__invalid.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                let data = ok_json.data;
+                assert!(!data.is_valid);
+                assert_eq!(data.error.unwrap().subcode, Some(463));
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/debug_token__reading__invalid_malformed.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                let data = ok_json.data;
+                assert!(!data.is_valid);
+                assert_eq!(data.app_id, None);
+                assert_eq!(data.error.unwrap().code, 190);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new("INPUT_TOKEN", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/debug_token?input_token=INPUT_TOKEN&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = Reading::new(
+            "INPUT_TOKEN",
+            AppAccessToken::from_app_id_and_secret(1, "APP_SECRET"),
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/debug_token?input_token=INPUT_TOKEN&access_token=1%7CAPP_SECRET"
+        );
+
+        let ep = Reading::new("INPUT_TOKEN", UserAccessToken::from("ACCESS_TOKEN"), None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/debug_token?input_token=INPUT_TOKEN&access_token=ACCESS_TOKEN"
+        );
+    }
+}
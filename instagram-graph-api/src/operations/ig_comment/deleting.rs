@@ -14,6 +14,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -28,7 +29,7 @@ pub struct Deleting {
 impl Deleting {
     pub fn new(
         ig_comment_id: u64,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -15,6 +15,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -30,7 +31,7 @@ pub struct Reading {
 impl Reading {
     pub fn new(
         ig_comment_id: u64,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -114,7 +115,7 @@ pub struct BulkReading {
 impl BulkReading {
     pub fn new(
         ig_comment_ids: &[u64],
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         assert!(!ig_comment_ids.is_empty());
@@ -1,2 +1,3 @@
 //
 pub mod creating;
+pub mod reading;
@@ -1 +1,3 @@
 pub mod reading;
+pub mod upload_status;
+pub mod uploading;
@@ -16,6 +16,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -30,7 +31,7 @@ pub struct Reading {
 impl Reading {
     pub fn new(
         ig_container_id: u64,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
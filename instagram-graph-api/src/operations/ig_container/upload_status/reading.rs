@@ -0,0 +1,136 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        RUPLOAD_URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+// Number of bytes received by rupload, resume an interrupted upload with
+// IgContainerUploading::offset
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub ig_container_id: u64,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        ig_container_id: u64,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_container_id,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/ig-api-upload/{}/{}",
+            RUPLOAD_URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_container_id,
+        );
+        let url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .header(AUTHORIZATION, format!("OAuth {}", self.access_token))
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub offset: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/rupload__ig_api_upload_0__upload_status__reading.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.offset, 4194304);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new(1, "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri(),
+            "https://rupload.facebook.com/ig-api-upload/v15.0/1"
+        );
+        assert_eq!(
+            req.headers().get(AUTHORIZATION).unwrap(),
+            "OAuth ACCESS_TOKEN"
+        );
+        assert!(req.body().is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        RUPLOAD_URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+// The IG Container must be created with upload_type=resumable,
+// see IgUserMediaCreatingWithResumableUpload
+#[derive(Debug, Clone)]
+pub struct Uploading {
+    pub ig_container_id: u64,
+    pub source: ValueUploadSource,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Uploading {
+    pub fn with_file_url(
+        ig_container_id: u64,
+        file_url: impl AsRef<str>,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_container_id,
+            source: ValueUploadSource::FileUrl(file_url.as_ref().into()),
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn with_bytes(
+        ig_container_id: u64,
+        bytes: Vec<u8>,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_container_id,
+            source: ValueUploadSource::Bytes {
+                file_size: bytes.len() as u64,
+                bytes,
+                offset: 0,
+            },
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    // Resume an interrupted bytes upload without loading the received part,
+    // bytes is the remaining part of the file starting at offset
+    pub fn with_remaining_bytes(
+        ig_container_id: u64,
+        bytes: Vec<u8>,
+        offset: u64,
+        file_size: u64,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_container_id,
+            source: ValueUploadSource::Bytes {
+                bytes,
+                offset,
+                file_size,
+            },
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    // Resume an interrupted bytes upload, value is the number of bytes already received,
+    // see IgContainerUploadStatusReading.
+    // Only applies to bytes sources, a file_url upload is fetched by rupload and can not be
+    // resumed, it is left unchanged. To resume without loading the received part, use
+    // with_remaining_bytes.
+    pub fn offset(mut self, value: u64) -> Self {
+        if let ValueUploadSource::Bytes { bytes, offset, .. } = &mut self.source {
+            // Drop the received bytes in place
+            let n = usize::try_from(value.saturating_sub(*offset))
+                .unwrap_or(usize::MAX)
+                .min(bytes.len());
+            bytes.drain(..n);
+            *offset = value;
+        }
+        self
+    }
+}
+
+impl Endpoint for Uploading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<UploadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/ig-api-upload/{}/{}",
+            RUPLOAD_URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_container_id,
+        );
+        let url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .header(AUTHORIZATION, format!("OAuth {}", self.access_token));
+
+        let request = match &self.source {
+            ValueUploadSource::FileUrl(file_url) => request
+                .header("file_url", file_url.as_ref())
+                .body(vec![])
+                .map_err(EndpointError::MakeRequestFailed)?,
+            ValueUploadSource::Bytes {
+                bytes,
+                offset,
+                file_size,
+            } => {
+                if offset.checked_add(bytes.len() as u64) != Some(*file_size) {
+                    return Err(EndpointError::Other("offset out of range".into()));
+                }
+
+                // Only the remaining bytes are kept, render_request takes &self so they are
+                // cloned into the body
+                request
+                    .header("offset", offset.to_string())
+                    .header("file_size", file_size.to_string())
+                    .body(bytes.to_owned())
+                    .map_err(EndpointError::MakeRequestFailed)?
+            }
+        };
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UploadingResponseBodyOkJson {
+    pub success: bool,
+    pub message: Option<Box<str>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UploadingResponseBodyErrJson {
+    pub debug_info: UploadingDebugInfo,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UploadingDebugInfo {
+    pub retriable: bool,
+    pub r#type: Box<str>,
+    pub message: Box<str>,
+}
+
+impl UploadingDebugInfo {
+    // From the body of EndpointRet::Other, rupload failures are not Graph API errors
+    pub fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<UploadingResponseBodyErrJson>(body)
+            .ok()
+            .map(|err_json| err_json.debug_info)
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub enum ValueUploadSource {
+    FileUrl(Box<str>),
+    // bytes is the part of the file starting at offset
+    Bytes {
+        bytes: Vec<u8>,
+        offset: u64,
+        file_size: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/rupload__ig_api_upload_0__uploading.json"
+        );
+        match serde_json::from_str::<UploadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert!(ok_json.success);
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/err__rupload__ig_api_upload_0__uploading.json"
+        );
+        match serde_json::from_str::<UploadingResponseBodyErrJson>(content) {
+            Ok(err_json) => {
+                // println!("{:?}", err_json);
+                assert!(!err_json.debug_info.retriable);
+                assert_eq!(err_json.debug_info.r#type.as_ref(), "ProcessingFailedError");
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_parse_response() {
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None);
+
+        //
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .body(
+                include_bytes!(
+                    "../../../tests/response_body_json_files/v14.0/rupload__ig_api_upload_0__uploading.json"
+                )
+                .to_vec(),
+            )
+            .unwrap();
+        match ep.parse_response(response).unwrap() {
+            EndpointRet::Ok(ok_json) => assert!(ok_json.success),
+            x => panic!("{x:?}"),
+        }
+
+        //
+        let response = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(
+                include_bytes!(
+                    "../../../tests/response_body_json_files/v14.0/err__rupload__ig_api_upload_0__uploading.json"
+                )
+                .to_vec(),
+            )
+            .unwrap();
+        match ep.parse_response(response).unwrap() {
+            EndpointRet::Other((status, Err(body))) => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                let debug_info = UploadingDebugInfo::from_body(&body).unwrap();
+                assert!(!debug_info.retriable);
+                assert_eq!(debug_info.r#type.as_ref(), "ProcessingFailedError");
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        //
+        let ep = Uploading::with_file_url(1, "https://example.com/1.mp4", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri(),
+            "https://rupload.facebook.com/ig-api-upload/v15.0/1"
+        );
+        assert_eq!(
+            req.headers().get(AUTHORIZATION).unwrap(),
+            "OAuth ACCESS_TOKEN"
+        );
+        assert_eq!(
+            req.headers().get("file_url").unwrap(),
+            "https://example.com/1.mp4"
+        );
+        assert!(req.body().is_empty());
+
+        //
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.headers().get("offset").unwrap(), "0");
+        assert_eq!(req.headers().get("file_size").unwrap(), "10");
+        assert_eq!(req.body(), b"0123456789");
+
+        //
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None).offset(4);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.headers().get("offset").unwrap(), "4");
+        assert_eq!(req.headers().get("file_size").unwrap(), "10");
+        assert_eq!(req.body(), b"456789");
+
+        //
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None)
+            .offset(4)
+            .offset(6);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.headers().get("offset").unwrap(), "6");
+        assert_eq!(req.body(), b"6789");
+
+        //
+        let ep =
+            Uploading::with_remaining_bytes(1, b"456789".to_vec(), 4, 10, "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.headers().get("offset").unwrap(), "4");
+        assert_eq!(req.headers().get("file_size").unwrap(), "10");
+        assert_eq!(req.body(), b"456789");
+
+        //
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None).offset(11);
+        assert!(ep.render_request().is_err());
+
+        //
+        let ep = Uploading::with_bytes(1, b"0123456789".to_vec(), "ACCESS_TOKEN", None)
+            .offset(4)
+            .offset(2);
+        assert!(ep.render_request().is_err());
+
+        //
+        let ep = Uploading::with_remaining_bytes(1, b"456".to_vec(), 4, 10, "ACCESS_TOKEN", None);
+        assert!(ep.render_request().is_err());
+    }
+}
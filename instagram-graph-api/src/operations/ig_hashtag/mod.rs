@@ -0,0 +1,3 @@
+//
+pub mod recent_media;
+pub mod top_media;
@@ -0,0 +1,204 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::{IgHashtag, ResponseBodyErrJson},
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub ig_user_id: u64,
+    pub q: Box<str>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        ig_user_id: u64,
+        q: impl AsRef<str>,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_user_id,
+            q: q.as_ref().into(),
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyRet>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/ig_hashtag_search",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("user_id", self.ig_user_id.to_string().as_str());
+        url.query_pairs_mut().append_pair("q", &self.q);
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(ReadingResponseBodyRet::OkJson(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            ))),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => {
+                    if let Some(err) = ExtInfoError::from_err_json(&err_json) {
+                        Ok(EndpointRet::Ok(ReadingResponseBodyRet::ExtInfoError(err)))
+                    } else {
+                        Ok(EndpointRet::Other((status, Ok(err_json))))
+                    }
+                }
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    pub data: Vec<IgHashtag>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ReadingResponseBodyRet {
+    OkJson(ReadingResponseBodyOkJson),
+    ExtInfoError(ExtInfoError),
+}
+
+impl ReadingResponseBodyRet {
+    pub fn as_ok_json(&self) -> Option<&ReadingResponseBodyOkJson> {
+        match self {
+            ReadingResponseBodyRet::OkJson(x) => Some(x),
+            ReadingResponseBodyRet::ExtInfoError(_) => None,
+        }
+    }
+}
+
+error_macro::r#enum! {
+    #[derive(Clone)]
+    pub enum ExtInfoError {
+        // A maximum of 30 unique hashtags can be queried within a rolling 7 day period
+        UniqueHashtagsLimitReached(Box<str>),
+    }
+}
+
+impl ExtInfoError {
+    pub fn from_err_json(err_json: &ResponseBodyErrJson) -> Option<Self> {
+        match (err_json.error.code, err_json.error.error_subcode) {
+            (24, Some(2207034)) => Some(Self::UniqueHashtagsLimitReached(
+                err_json
+                    .error
+                    .error_user_msg
+                    .as_deref()
+                    .unwrap_or_else(|| err_json.error.message.as_ref())
+                    .into(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/ig_hashtag_search__reading__q_coke.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.data[0].id, 17843826142012701);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new(1, "coke", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/ig_hashtag_search?user_id=1&q=coke&access_token=ACCESS_TOKEN"
+        );
+    }
+
+    #[test]
+    fn test_ext_info_error() {
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/err__ig_hashtag_search__reading__unique_hashtags_limit_reached.json"
+        );
+        match serde_json::from_str::<ResponseBodyErrJson>(content) {
+            Ok(err_json) => match ExtInfoError::from_err_json(&err_json) {
+                Some(ExtInfoError::UniqueHashtagsLimitReached(msg)) => {
+                    println!("{msg}");
+                }
+                ret => panic!("{ret:?}"),
+            },
+            Err(err) => panic!("{}", err),
+        }
+
+        // Rate limiting is not the unique hashtags limit
+        let content = r#"{"error":{"message":"(#4) Application request limit reached","type":"OAuthException","is_transient":true,"code":4,"error_user_title":"Hashtag search limit reached"}}"#;
+        match serde_json::from_str::<ResponseBodyErrJson>(content) {
+            Ok(err_json) => assert!(ExtInfoError::from_err_json(&err_json).is_none()),
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -29,7 +30,7 @@ impl Creating {
     pub fn new(
         ig_media_id: u64,
         message: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -15,6 +15,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -31,7 +32,7 @@ pub struct Reading {
 impl Reading {
     pub fn new(
         ig_media_id: u64,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -137,6 +138,20 @@ mod tests {
             }
             Err(err) => panic!("{}", err),
         }
+
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_media_17854587811139572__comments__reading__live.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.data.len(), 2);
+                assert!(!ok_json.data[0].hidden);
+                assert_eq!(ok_json.data[0].like_count, 0);
+            }
+            Err(err) => panic!("{}", err),
+        }
     }
 
     #[test]
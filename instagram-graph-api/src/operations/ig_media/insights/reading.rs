@@ -0,0 +1,217 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::{
+        insights::{IgMediaInsightsMetric, InsightsBreakdown, InsightsPeriod},
+        IgMediaInsights, ResponseBodyErrJson,
+    },
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub ig_media_id: u64,
+    pub metric: Vec<IgMediaInsightsMetric>,
+    pub period: Option<InsightsPeriod>,
+    // Only story_navigation_action_type, and only with the navigation metric
+    pub breakdown: Option<Vec<InsightsBreakdown>>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        ig_media_id: u64,
+        metric: &[IgMediaInsightsMetric],
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        assert!(!metric.is_empty());
+
+        Self {
+            ig_media_id,
+            metric: metric.to_owned(),
+            period: None,
+            breakdown: None,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn period(mut self, value: InsightsPeriod) -> Self {
+        self.period = Some(value);
+        self
+    }
+
+    pub fn breakdown(mut self, value: Vec<InsightsBreakdown>) -> Self {
+        self.breakdown = Some(value);
+        self
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/insights",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_media_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut().append_pair(
+            "metric",
+            self.metric
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+                .as_str(),
+        );
+
+        if let Some(period) = &self.period {
+            url.query_pairs_mut()
+                .append_pair("period", period.to_string().as_str());
+        }
+        if let Some(breakdown) = &self.breakdown {
+            url.query_pairs_mut().append_pair(
+                "breakdown",
+                breakdown
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .as_str(),
+            );
+        }
+
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    pub data: Vec<IgMediaInsights>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_media_17946328927974136__insights__reading.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.data.len(), 3);
+                assert_eq!(ok_json.data[0].name, IgMediaInsightsMetric::Impressions);
+                assert_eq!(ok_json.data[0].period, InsightsPeriod::Lifetime);
+                assert_eq!(ok_json.data[2].name, IgMediaInsightsMetric::Saved);
+                assert_eq!(ok_json.data[2].values[0].value.as_number(), Some(1));
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new(
+            1,
+            &[
+                IgMediaInsightsMetric::Impressions,
+                IgMediaInsightsMetric::Reach,
+                IgMediaInsightsMetric::Saved,
+            ],
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=impressions%2Creach%2Csaved&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = Reading::new(
+            1,
+            &[IgMediaInsightsMetric::Navigation],
+            "ACCESS_TOKEN",
+            None,
+        )
+        .breakdown(vec![InsightsBreakdown::StoryNavigationActionType]);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=navigation&breakdown=story_navigation_action_type&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = Reading::new(
+            1,
+            &[IgMediaInsightsMetric::Other("foo&bar#baz".into())],
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=foo%26bar%23baz&access_token=ACCESS_TOKEN"
+        );
+    }
+}
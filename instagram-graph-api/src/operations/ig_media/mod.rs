@@ -1,3 +1,8 @@
 //
+pub mod children;
+pub mod collaborators;
 pub mod comments;
+pub mod insights;
+pub mod product_tags;
+pub mod reading;
 pub mod updating;
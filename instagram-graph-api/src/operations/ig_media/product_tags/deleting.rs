@@ -0,0 +1,145 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        ig_user::media::creating::ValueProductTag,
+        URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Deleting {
+    pub ig_media_id: u64,
+    pub deleted_tags: Vec<ValueProductTag>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Deleting {
+    pub fn new(
+        ig_media_id: u64,
+        deleted_tags: Vec<ValueProductTag>,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_media_id,
+            deleted_tags,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for Deleting {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<DeletingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/product_tags",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_media_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut().append_pair(
+            "deleted_tags",
+            serde_json::to_string(&self.deleted_tags)
+                .map_err(|_| EndpointError::Other("ser deleted_tags failed".into()))?
+                .as_ref(),
+        );
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::DELETE)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeletingResponseBodyOkJson {
+    pub success: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_media_0__product_tags__deleting_sample.json"
+        );
+        match serde_json::from_str::<DeletingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert!(ok_json.success);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Deleting::new(
+            1,
+            vec![ValueProductTag::new(2, 0.5, 0.8)],
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::DELETE);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/product_tags?deleted_tags=%5B%7B%22product_id%22%3A%222%22%2C%22x%22%3A0.5%2C%22y%22%3A0.8%7D%5D&access_token=ACCESS_TOKEN"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//
+pub mod creating;
+pub mod deleting;
+pub mod reading;
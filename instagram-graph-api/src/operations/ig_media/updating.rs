@@ -14,6 +14,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -30,7 +31,7 @@ impl UpdatingWithEnableOrDisableComments {
     pub fn new(
         ig_media_id: u64,
         comment_enabled: bool,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -15,6 +15,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -33,7 +34,7 @@ impl Reading {
     pub fn new(
         ig_user_id: u64,
         username: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
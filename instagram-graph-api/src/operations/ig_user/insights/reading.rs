@@ -0,0 +1,287 @@
+use chrono::{DateTime, Utc};
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::{
+        insights::{
+            IgUserInsightsMetric, InsightsBreakdown, InsightsMetricType, InsightsPaging,
+            InsightsPeriod,
+        },
+        IgUserInsights, ResponseBodyErrJson,
+    },
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub ig_user_id: u64,
+    pub metric: Vec<IgUserInsightsMetric>,
+    pub period: InsightsPeriod,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub metric_type: Option<InsightsMetricType>,
+    pub breakdown: Option<Vec<InsightsBreakdown>>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        ig_user_id: u64,
+        metric: &[IgUserInsightsMetric],
+        period: InsightsPeriod,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        assert!(!metric.is_empty());
+
+        Self {
+            ig_user_id,
+            metric: metric.to_owned(),
+            period,
+            since: None,
+            until: None,
+            metric_type: None,
+            breakdown: None,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn since(mut self, value: DateTime<Utc>) -> Self {
+        self.since = Some(value);
+        self
+    }
+
+    pub fn until(mut self, value: DateTime<Utc>) -> Self {
+        self.until = Some(value);
+        self
+    }
+
+    pub fn metric_type(mut self, value: InsightsMetricType) -> Self {
+        self.metric_type = Some(value);
+        self
+    }
+
+    pub fn breakdown(mut self, value: Vec<InsightsBreakdown>) -> Self {
+        self.breakdown = Some(value);
+        self
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/insights",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_user_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut().append_pair(
+            "metric",
+            self.metric
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+                .as_str(),
+        );
+
+        url.query_pairs_mut()
+            .append_pair("period", self.period.to_string().as_str());
+        if let Some(metric_type) = &self.metric_type {
+            url.query_pairs_mut()
+                .append_pair("metric_type", metric_type.to_string().as_str());
+        }
+        if let Some(breakdown) = &self.breakdown {
+            url.query_pairs_mut().append_pair(
+                "breakdown",
+                breakdown
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .as_str(),
+            );
+        }
+        if let Some(since) = &self.since {
+            url.query_pairs_mut()
+                .append_pair("since", since.timestamp().to_string().as_str());
+        }
+        if let Some(until) = &self.until {
+            url.query_pairs_mut()
+                .append_pair("until", until.timestamp().to_string().as_str());
+        }
+
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    pub data: Vec<IgUserInsights>,
+    pub paging: Option<InsightsPaging>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_user_0__insights__reading__time_series.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.data.len(), 2);
+                assert_eq!(ok_json.data[0].name, IgUserInsightsMetric::Impressions);
+                assert_eq!(ok_json.data[0].period, InsightsPeriod::Day);
+                assert_eq!(ok_json.data[0].values[1].value.as_number(), Some(32));
+                assert!(ok_json.data[0].values[1].end_time.is_some());
+                assert!(ok_json.paging.unwrap().next.is_some());
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_user_0__insights__reading__total_value.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                let total_value = ok_json.data[0].total_value.as_ref().unwrap();
+                assert_eq!(total_value.value, 103);
+                assert_eq!(
+                    total_value.breakdowns[0].dimension_keys,
+                    vec![InsightsBreakdown::MediaProductType]
+                );
+                assert_eq!(total_value.breakdowns[0].results.len(), 2);
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_user_0__insights__reading__audience_city.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.data[0].name, IgUserInsightsMetric::AudienceCity);
+                assert_eq!(ok_json.data[0].period, InsightsPeriod::Lifetime);
+                let value = ok_json.data[0].values[0].value.as_map().unwrap();
+                assert_eq!(value.get("London, England"), Some(&3));
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new(
+            1,
+            &[
+                IgUserInsightsMetric::Impressions,
+                IgUserInsightsMetric::Reach,
+            ],
+            InsightsPeriod::Day,
+            "ACCESS_TOKEN",
+            None,
+        )
+        .since("2022-01-01T00:00:00Z".parse().unwrap())
+        .until("2022-01-02T00:00:00Z".parse().unwrap());
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=impressions%2Creach&period=day&since=1640995200&until=1641081600&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = Reading::new(
+            1,
+            &[IgUserInsightsMetric::Reach],
+            InsightsPeriod::Day,
+            "ACCESS_TOKEN",
+            None,
+        )
+        .metric_type(InsightsMetricType::TotalValue)
+        .breakdown(vec![InsightsBreakdown::MediaProductType]);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=reach&period=day&metric_type=total_value&breakdown=media_product_type&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = Reading::new(
+            1,
+            &[IgUserInsightsMetric::Other("foo&bar#baz".into())],
+            InsightsPeriod::Day,
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/insights?metric=foo%26bar%23baz&period=day&access_token=ACCESS_TOKEN"
+        );
+    }
+}
@@ -8,6 +8,7 @@ use http_api_client_endpoint::{
     Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
 };
 use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
 use url::Url;
 
 use crate::{
@@ -16,6 +17,7 @@ use crate::{
         common::{EndpointError, EndpointRet},
         URL_BASE, VERSION,
     },
+    types::IgAccessToken,
 };
 
 //
@@ -27,6 +29,10 @@ pub struct CreatingWithImage {
     // Require page.location.latitude present and page.location.longitude present
     pub location_id: Option<u64>,
     pub user_tags: Option<Vec<ValueUserTag>>,
+    pub product_tags: Option<Vec<ValueProductTag>>,
+    pub alt_text: Option<Box<str>>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -36,7 +42,7 @@ impl CreatingWithImage {
     pub fn new(
         ig_user_id: u64,
         image_url: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -45,6 +51,9 @@ impl CreatingWithImage {
             caption: None,
             location_id: None,
             user_tags: None,
+            product_tags: None,
+            alt_text: None,
+            collaborators: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -64,6 +73,21 @@ impl CreatingWithImage {
         self.user_tags = Some(value);
         self
     }
+
+    pub fn product_tags(mut self, value: Vec<ValueProductTag>) -> Self {
+        self.product_tags = Some(value);
+        self
+    }
+
+    pub fn alt_text(mut self, value: impl AsRef<str>) -> Self {
+        self.alt_text = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
 }
 
 impl Endpoint for CreatingWithImage {
@@ -102,6 +126,26 @@ impl Endpoint for CreatingWithImage {
                     .as_ref(),
             );
         }
+        if let Some(product_tags) = &self.product_tags {
+            url.query_pairs_mut().append_pair(
+                "product_tags",
+                serde_json::to_string(&product_tags)
+                    .map_err(|_| EndpointError::Other("ser product_tags failed".into()))?
+                    .as_ref(),
+            );
+        }
+        if let Some(alt_text) = &self.alt_text {
+            url.query_pairs_mut().append_pair("alt_text", alt_text);
+        }
+        if let Some(collaborators) = &self.collaborators {
+            check_collaborators(collaborators)?;
+            url.query_pairs_mut().append_pair(
+                "collaborators",
+                serde_json::to_string(&collaborators)
+                    .map_err(|_| EndpointError::Other("ser collaborators failed".into()))?
+                    .as_ref(),
+            );
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -131,6 +175,8 @@ pub struct CreatingWithCarouselItemImage {
     pub ig_user_id: u64,
     pub image_url: Box<str>,
     pub user_tags: Option<Vec<ValueUserTag>>,
+    pub product_tags: Option<Vec<ValueProductTag>>,
+    pub alt_text: Option<Box<str>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -140,13 +186,15 @@ impl CreatingWithCarouselItemImage {
     pub fn new(
         ig_user_id: u64,
         image_url: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
             ig_user_id,
             image_url: image_url.as_ref().into(),
             user_tags: None,
+            product_tags: None,
+            alt_text: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -156,6 +204,16 @@ impl CreatingWithCarouselItemImage {
         self.user_tags = Some(value);
         self
     }
+
+    pub fn product_tags(mut self, value: Vec<ValueProductTag>) -> Self {
+        self.product_tags = Some(value);
+        self
+    }
+
+    pub fn alt_text(mut self, value: impl AsRef<str>) -> Self {
+        self.alt_text = Some(value.as_ref().into());
+        self
+    }
 }
 
 impl Endpoint for CreatingWithCarouselItemImage {
@@ -187,6 +245,17 @@ impl Endpoint for CreatingWithCarouselItemImage {
                     .as_ref(),
             );
         }
+        if let Some(product_tags) = &self.product_tags {
+            url.query_pairs_mut().append_pair(
+                "product_tags",
+                serde_json::to_string(&product_tags)
+                    .map_err(|_| EndpointError::Other("ser product_tags failed".into()))?
+                    .as_ref(),
+            );
+        }
+        if let Some(alt_text) = &self.alt_text {
+            url.query_pairs_mut().append_pair("alt_text", alt_text);
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -218,6 +287,9 @@ pub struct CreatingWithVideo {
     pub caption: Option<Box<str>>,
     pub location_id: Option<u64>,
     pub thumb_offset: Option<u64>,
+    pub product_tags: Option<Vec<ValueProductTag>>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -227,7 +299,7 @@ impl CreatingWithVideo {
     pub fn new(
         ig_user_id: u64,
         video_url: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -236,6 +308,8 @@ impl CreatingWithVideo {
             caption: None,
             location_id: None,
             thumb_offset: None,
+            product_tags: None,
+            collaborators: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -255,6 +329,17 @@ impl CreatingWithVideo {
         self.thumb_offset = Some(value);
         self
     }
+
+    // Product tags on videos have no position, x and y should be None
+    pub fn product_tags(mut self, value: Vec<ValueProductTag>) -> Self {
+        self.product_tags = Some(value);
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
 }
 
 impl Endpoint for CreatingWithVideo {
@@ -290,6 +375,23 @@ impl Endpoint for CreatingWithVideo {
             url.query_pairs_mut()
                 .append_pair("thumb_offset", thumb_offset.to_string().as_ref());
         }
+        if let Some(product_tags) = &self.product_tags {
+            url.query_pairs_mut().append_pair(
+                "product_tags",
+                serde_json::to_string(&product_tags)
+                    .map_err(|_| EndpointError::Other("ser product_tags failed".into()))?
+                    .as_ref(),
+            );
+        }
+        if let Some(collaborators) = &self.collaborators {
+            check_collaborators(collaborators)?;
+            url.query_pairs_mut().append_pair(
+                "collaborators",
+                serde_json::to_string(&collaborators)
+                    .map_err(|_| EndpointError::Other("ser collaborators failed".into()))?
+                    .as_ref(),
+            );
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -319,6 +421,7 @@ pub struct CreatingWithCarouselItemVideo {
     pub ig_user_id: u64,
     pub video_url: Box<str>,
     pub thumb_offset: Option<u64>,
+    pub user_tags: Option<Vec<ValueUserTag>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -328,13 +431,14 @@ impl CreatingWithCarouselItemVideo {
     pub fn new(
         ig_user_id: u64,
         video_url: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
             ig_user_id,
             video_url: video_url.as_ref().into(),
             thumb_offset: None,
+            user_tags: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -344,6 +448,11 @@ impl CreatingWithCarouselItemVideo {
         self.thumb_offset = Some(value);
         self
     }
+
+    pub fn user_tags(mut self, value: Vec<ValueUserTag>) -> Self {
+        self.user_tags = Some(value);
+        self
+    }
 }
 
 impl Endpoint for CreatingWithCarouselItemVideo {
@@ -372,6 +481,14 @@ impl Endpoint for CreatingWithCarouselItemVideo {
             url.query_pairs_mut()
                 .append_pair("thumb_offset", thumb_offset.to_string().as_ref());
         }
+        if let Some(user_tags) = &self.user_tags {
+            url.query_pairs_mut().append_pair(
+                "user_tags",
+                serde_json::to_string(&user_tags)
+                    .map_err(|_| EndpointError::Other("ser user_tags failed".into()))?
+                    .as_ref(),
+            );
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -402,6 +519,9 @@ pub struct CreatingWithCarousel {
     pub children: Vec<u64>,
     pub caption: Option<Box<str>>,
     pub location_id: Option<u64>,
+    pub share_to_feed: Option<bool>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -411,7 +531,7 @@ impl CreatingWithCarousel {
     pub fn new(
         ig_user_id: u64,
         children: Vec<u64>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -419,6 +539,8 @@ impl CreatingWithCarousel {
             children,
             caption: None,
             location_id: None,
+            share_to_feed: None,
+            collaborators: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -433,6 +555,16 @@ impl CreatingWithCarousel {
         self.location_id = Some(value);
         self
     }
+
+    pub fn share_to_feed(mut self, value: bool) -> Self {
+        self.share_to_feed = Some(value);
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
 }
 
 impl Endpoint for CreatingWithCarousel {
@@ -466,6 +598,19 @@ impl Endpoint for CreatingWithCarousel {
             url.query_pairs_mut()
                 .append_pair("location_id", location_id.to_string().as_ref());
         }
+        if let Some(share_to_feed) = &self.share_to_feed {
+            url.query_pairs_mut()
+                .append_pair("share_to_feed", share_to_feed.to_string().as_ref());
+        }
+        if let Some(collaborators) = &self.collaborators {
+            check_collaborators(collaborators)?;
+            url.query_pairs_mut().append_pair(
+                "collaborators",
+                serde_json::to_string(&collaborators)
+                    .map_err(|_| EndpointError::Other("ser collaborators failed".into()))?
+                    .as_ref(),
+            );
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -498,6 +643,12 @@ pub struct CreatingWithReels {
     pub location_id: Option<u64>,
     pub thumb_offset: Option<u64>,
     pub share_to_feed: Option<bool>,
+    // If set, thumb_offset is ignored
+    pub cover_url: Option<Box<str>>,
+    // Can only be set once, at creation
+    pub audio_name: Option<Box<str>>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
     //
     pub access_token: Box<str>,
     pub version: Option<Box<str>>,
@@ -507,7 +658,7 @@ impl CreatingWithReels {
     pub fn new(
         ig_user_id: u64,
         video_url: impl AsRef<str>,
-        access_token: impl AsRef<str>,
+        access_token: impl IgAccessToken,
         version: impl Into<Option<Box<str>>>,
     ) -> Self {
         Self {
@@ -517,6 +668,9 @@ impl CreatingWithReels {
             location_id: None,
             thumb_offset: None,
             share_to_feed: None,
+            cover_url: None,
+            audio_name: None,
+            collaborators: None,
             access_token: access_token.as_ref().into(),
             version: version.into(),
         }
@@ -541,6 +695,21 @@ impl CreatingWithReels {
         self.share_to_feed = Some(value);
         self
     }
+
+    pub fn cover_url(mut self, value: impl AsRef<str>) -> Self {
+        self.cover_url = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn audio_name(mut self, value: impl AsRef<str>) -> Self {
+        self.audio_name = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
 }
 
 impl Endpoint for CreatingWithReels {
@@ -578,6 +747,21 @@ impl Endpoint for CreatingWithReels {
             url.query_pairs_mut()
                 .append_pair("share_to_feed", share_to_feed.to_string().as_ref());
         }
+        if let Some(cover_url) = &self.cover_url {
+            url.query_pairs_mut().append_pair("cover_url", cover_url);
+        }
+        if let Some(audio_name) = &self.audio_name {
+            url.query_pairs_mut().append_pair("audio_name", audio_name);
+        }
+        if let Some(collaborators) = &self.collaborators {
+            check_collaborators(collaborators)?;
+            url.query_pairs_mut().append_pair(
+                "collaborators",
+                serde_json::to_string(&collaborators)
+                    .map_err(|_| EndpointError::Other("ser collaborators failed".into()))?
+                    .as_ref(),
+            );
+        }
 
         url.query_pairs_mut()
             .append_pair("access_token", &self.access_token);
@@ -601,6 +785,275 @@ impl Endpoint for CreatingWithReels {
     }
 }
 
+//
+#[derive(Debug, Clone)]
+pub struct CreatingWithStory {
+    pub ig_user_id: u64,
+    pub media: ValueStoryMedia,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl CreatingWithStory {
+    pub fn new(
+        ig_user_id: u64,
+        media: ValueStoryMedia,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_user_id,
+            media,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for CreatingWithStory {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<CreatingResponseBodyRet>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/media?fields={}",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_user_id,
+            IgContainer::fields(),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut().append_pair("media_type", "STORIES");
+        match &self.media {
+            ValueStoryMedia::ImageUrl(image_url) => {
+                url.query_pairs_mut().append_pair("image_url", image_url);
+            }
+            ValueStoryMedia::VideoUrl(video_url) => {
+                url.query_pairs_mut().append_pair("video_url", video_url);
+            }
+        }
+
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        parse_response(response)
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub struct CreatingWithResumableUpload {
+    pub ig_user_id: u64,
+    pub media_type: ValueResumableUploadMediaType,
+    pub caption: Option<Box<str>>,
+    pub location_id: Option<u64>,
+    pub thumb_offset: Option<u64>,
+    // Reels only
+    pub share_to_feed: Option<bool>,
+    pub cover_url: Option<Box<str>>,
+    pub audio_name: Option<Box<str>>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
+    // Video only
+    pub is_carousel_item: Option<bool>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl CreatingWithResumableUpload {
+    pub fn new(
+        ig_user_id: u64,
+        media_type: ValueResumableUploadMediaType,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_user_id,
+            media_type,
+            caption: None,
+            location_id: None,
+            thumb_offset: None,
+            share_to_feed: None,
+            cover_url: None,
+            audio_name: None,
+            collaborators: None,
+            is_carousel_item: None,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn caption(mut self, value: impl AsRef<str>) -> Self {
+        self.caption = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn location_id(mut self, value: u64) -> Self {
+        self.location_id = Some(value);
+        self
+    }
+
+    pub fn thumb_offset(mut self, value: u64) -> Self {
+        self.thumb_offset = Some(value);
+        self
+    }
+
+    pub fn share_to_feed(mut self, value: bool) -> Self {
+        self.share_to_feed = Some(value);
+        self
+    }
+
+    pub fn cover_url(mut self, value: impl AsRef<str>) -> Self {
+        self.cover_url = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn audio_name(mut self, value: impl AsRef<str>) -> Self {
+        self.audio_name = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
+
+    pub fn is_carousel_item(mut self, value: bool) -> Self {
+        self.is_carousel_item = Some(value);
+        self
+    }
+}
+
+impl Endpoint for CreatingWithResumableUpload {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ResumableUploadCreatingResponseBodyRet>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        // No fields params, the uri of the upload session is only in the default response
+        let url = format!(
+            "{}/{}/{}/media",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_user_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("media_type", self.media_type.as_str());
+        url.query_pairs_mut()
+            .append_pair("upload_type", "resumable");
+
+        if let Some(caption) = &self.caption {
+            url.query_pairs_mut().append_pair("caption", caption);
+        }
+        if let Some(location_id) = &self.location_id {
+            url.query_pairs_mut()
+                .append_pair("location_id", location_id.to_string().as_ref());
+        }
+        if let Some(thumb_offset) = &self.thumb_offset {
+            url.query_pairs_mut()
+                .append_pair("thumb_offset", thumb_offset.to_string().as_ref());
+        }
+        if let Some(share_to_feed) = &self.share_to_feed {
+            if !matches!(self.media_type, ValueResumableUploadMediaType::Reels) {
+                return Err(EndpointError::Other(
+                    "share_to_feed is only available on REELS".into(),
+                ));
+            }
+            url.query_pairs_mut()
+                .append_pair("share_to_feed", share_to_feed.to_string().as_ref());
+        }
+        if let Some(cover_url) = &self.cover_url {
+            url.query_pairs_mut().append_pair("cover_url", cover_url);
+        }
+        if let Some(audio_name) = &self.audio_name {
+            url.query_pairs_mut().append_pair("audio_name", audio_name);
+        }
+        if let Some(collaborators) = &self.collaborators {
+            check_collaborators(collaborators)?;
+            url.query_pairs_mut().append_pair(
+                "collaborators",
+                serde_json::to_string(&collaborators)
+                    .map_err(|_| EndpointError::Other("ser collaborators failed".into()))?
+                    .as_ref(),
+            );
+        }
+        if let Some(is_carousel_item) = &self.is_carousel_item {
+            if !matches!(self.media_type, ValueResumableUploadMediaType::Video) {
+                return Err(EndpointError::Other(
+                    "is_carousel_item is only available on VIDEO".into(),
+                ));
+            }
+            url.query_pairs_mut()
+                .append_pair("is_carousel_item", is_carousel_item.to_string().as_ref());
+        }
+
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map(ResumableUploadCreatingResponseBodyRet::OkJson)
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => {
+                    if let Some(err) = ExtInfoError::from_err_json(&err_json) {
+                        Ok(EndpointRet::Ok(
+                            ResumableUploadCreatingResponseBodyRet::ExtInfoError(err),
+                        ))
+                    } else {
+                        Ok(EndpointRet::Other((status, Ok(err_json))))
+                    }
+                }
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
 //
 //
 //
@@ -685,6 +1138,31 @@ impl CreatingResponseBodyRet {
     }
 }
 
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ResumableUploadCreatingResponseBodyOkJson {
+    // IG Container id
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // e.g. https://rupload.facebook.com/ig-api-upload/v15.0/{ig-container-id}
+    pub uri: Box<str>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ResumableUploadCreatingResponseBodyRet {
+    OkJson(ResumableUploadCreatingResponseBodyOkJson),
+    ExtInfoError(ExtInfoError),
+}
+
+impl ResumableUploadCreatingResponseBodyRet {
+    pub fn as_ok_json(&self) -> Option<&ResumableUploadCreatingResponseBodyOkJson> {
+        match self {
+            ResumableUploadCreatingResponseBodyRet::OkJson(x) => Some(x),
+            ResumableUploadCreatingResponseBodyRet::ExtInfoError(_) => None,
+        }
+    }
+}
+
 //
 //
 //
@@ -714,6 +1192,23 @@ fn parse_response(
     }
 }
 
+//
+pub const COLLABORATORS_MAX: usize = 3;
+
+fn check_collaborators(collaborators: &[Box<str>]) -> Result<(), EndpointError> {
+    if collaborators.len() > COLLABORATORS_MAX {
+        return Err(EndpointError::Other(
+            format!("collaborators max {COLLABORATORS_MAX}").into(),
+        ));
+    }
+    if collaborators.iter().any(|x| x.is_empty()) {
+        return Err(EndpointError::Other(
+            "collaborators has empty username".into(),
+        ));
+    }
+    Ok(())
+}
+
 //
 #[derive(Serialize, Debug, Clone)]
 pub struct ValueUserTag {
@@ -731,6 +1226,64 @@ impl ValueUserTag {
     }
 }
 
+//
+#[derive(Serialize, Debug, Clone)]
+pub struct ValueProductTag {
+    #[serde(serialize_with = "serialize_u64_as_str")]
+    pub product_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<f64>,
+}
+impl ValueProductTag {
+    pub fn new(product_id: u64, x: impl Into<Option<f64>>, y: impl Into<Option<f64>>) -> Self {
+        Self {
+            product_id,
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+}
+
+fn serialize_u64_as_str<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(value)
+}
+
+//
+#[derive(Debug, Clone)]
+pub enum ValueResumableUploadMediaType {
+    Reels,
+    Video,
+}
+impl ValueResumableUploadMediaType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Reels => "REELS",
+            Self::Video => "VIDEO",
+        }
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub enum ValueStoryMedia {
+    ImageUrl(Box<str>),
+    VideoUrl(Box<str>),
+}
+impl ValueStoryMedia {
+    pub fn image_url(value: impl AsRef<str>) -> Self {
+        Self::ImageUrl(value.as_ref().into())
+    }
+
+    pub fn video_url(value: impl AsRef<str>) -> Self {
+        Self::VideoUrl(value.as_ref().into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -749,9 +1302,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_de_resumable_upload_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_user_0__media__creating__resumable_upload.json"
+        );
+        match serde_json::from_str::<ResumableUploadCreatingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.id, 17889615814797912);
+                assert!(ok_json.uri.ends_with("/17889615814797912"));
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
     #[test]
     fn test_endpoint_render_request() {
-        // TODO,
+        //
+        let ep = CreatingWithStory::new(
+            1,
+            ValueStoryMedia::image_url("https://example.com/1.jpg"),
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?fields=id,status,status_code&media_type=STORIES&image_url=https%3A%2F%2Fexample.com%2F1.jpg&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithStory::new(
+            1,
+            ValueStoryMedia::video_url("https://example.com/1.mp4"),
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?fields=id,status,status_code&media_type=STORIES&video_url=https%3A%2F%2Fexample.com%2F1.mp4&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithImage::new(1, "https://example.com/1.jpg", "ACCESS_TOKEN", None)
+            .product_tags(vec![ValueProductTag::new(2, 0.5, 0.8)]);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?fields=id,status,status_code&image_url=https%3A%2F%2Fexample.com%2F1.jpg&is_carousel_item=false&product_tags=%5B%7B%22product_id%22%3A%222%22%2C%22x%22%3A0.5%2C%22y%22%3A0.8%7D%5D&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithVideo::new(1, "https://example.com/1.mp4", "ACCESS_TOKEN", None)
+            .product_tags(vec![ValueProductTag::new(2, None, None)]);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?fields=id,status,status_code&media_type=VIDEO&video_url=https%3A%2F%2Fexample.com%2F1.mp4&is_carousel_item=false&product_tags=%5B%7B%22product_id%22%3A%222%22%7D%5D&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithReels::new(1, "https://example.com/1.mp4", "ACCESS_TOKEN", None)
+            .cover_url("https://example.com/1.jpg")
+            .audio_name("foo")
+            .collaborators(vec!["bar"]);
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?fields=id,status,status_code&media_type=REELS&video_url=https%3A%2F%2Fexample.com%2F1.mp4&cover_url=https%3A%2F%2Fexample.com%2F1.jpg&audio_name=foo&collaborators=%5B%22bar%22%5D&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithResumableUpload::new(
+            1,
+            ValueResumableUploadMediaType::Reels,
+            "ACCESS_TOKEN",
+            None,
+        )
+        .caption("foo")
+        .share_to_feed(true);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/media?media_type=REELS&upload_type=resumable&caption=foo&share_to_feed=true&access_token=ACCESS_TOKEN"
+        );
+        let ep = CreatingWithResumableUpload::new(
+            1,
+            ValueResumableUploadMediaType::Reels,
+            "ACCESS_TOKEN",
+            None,
+        )
+        .is_carousel_item(true);
+        assert!(ep.render_request().is_err());
+
+        //
+        let ep = CreatingWithImage::new(1, "https://example.com/1.jpg", "ACCESS_TOKEN", None)
+            .alt_text("foo")
+            .collaborators(vec!["a", "b", "c", "d"]);
+        match ep.render_request() {
+            Err(EndpointError::Other(err)) => {
+                assert_eq!(err.to_string(), "collaborators max 3");
+            }
+            ret => panic!("{ret:?}"),
+        }
     }
 
     #[test]
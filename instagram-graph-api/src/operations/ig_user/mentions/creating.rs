@@ -0,0 +1,164 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::IgAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Creating {
+    pub ig_user_id: u64,
+    pub media_id: u64,
+    // Set when replying to a comment mention, otherwise replying to a caption mention
+    pub comment_id: Option<u64>,
+    pub message: Box<str>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Creating {
+    pub fn new(
+        ig_user_id: u64,
+        media_id: u64,
+        message: impl AsRef<str>,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_user_id,
+            media_id,
+            comment_id: None,
+            message: message.as_ref().into(),
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn comment_id(mut self, value: u64) -> Self {
+        self.comment_id = Some(value);
+        self
+    }
+}
+
+impl Endpoint for Creating {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<CreatingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/mentions",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.ig_user_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("media_id", self.media_id.to_string().as_str());
+        if let Some(comment_id) = &self.comment_id {
+            url.query_pairs_mut()
+                .append_pair("comment_id", comment_id.to_string().as_str());
+        }
+        url.query_pairs_mut().append_pair("message", &self.message);
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CreatingResponseBodyOkJson {
+    // IG Comment id
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/ig_user_0__mentions__creating_sample.json"
+        );
+        match serde_json::from_str::<CreatingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert!(ok_json.id > 0);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        //
+        let ep = Creating::new(1, 2, "Thanks!", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/mentions?media_id=2&message=Thanks%21&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = Creating::new(1, 2, "foo", "ACCESS_TOKEN", None).comment_id(3);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/mentions?media_id=2&comment_id=3&message=foo&access_token=ACCESS_TOKEN"
+        );
+    }
+}
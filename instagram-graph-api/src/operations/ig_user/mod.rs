@@ -1,4 +1,17 @@
 //
+pub mod available_catalogs;
 pub mod business_discovery;
+pub mod catalog_product_search;
+pub mod content_publishing_limit;
+pub mod insights;
+pub mod live_media;
 pub mod media;
 pub mod media_publish;
+pub mod mentioned_comment;
+pub mod mentioned_media;
+pub mod mentions;
+pub mod product_appeal;
+pub mod reading;
+pub mod recently_searched_hashtags;
+pub mod stories;
+pub mod tags;
@@ -0,0 +1,3 @@
+//
+pub mod creating;
+pub mod reading;
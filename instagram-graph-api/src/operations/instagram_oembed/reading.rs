@@ -0,0 +1,173 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use url::Url;
+
+use crate::{
+    objects::{InstagramOembed, ResponseBodyErrJson},
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::AppLevelAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct Reading {
+    // Post, reel or IGTV permalink
+    pub url: Box<str>,
+    // Min 320
+    pub maxwidth: Option<u32>,
+    pub omitscript: Option<bool>,
+    pub hidecaption: Option<bool>,
+    //
+    // App access token, format is {app-id}|{client-token}
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Reading {
+    pub fn new(
+        url: impl AsRef<str>,
+        access_token: impl AppLevelAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            url: url.as_ref().into(),
+            maxwidth: None,
+            omitscript: None,
+            hidecaption: None,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn maxwidth(mut self, value: u32) -> Self {
+        self.maxwidth = Some(value);
+        self
+    }
+
+    pub fn omitscript(mut self, value: bool) -> Self {
+        self.omitscript = Some(value);
+        self
+    }
+
+    pub fn hidecaption(mut self, value: bool) -> Self {
+        self.hidecaption = Some(value);
+        self
+    }
+}
+
+impl Endpoint for Reading {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/instagram_oembed",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut().append_pair("url", &self.url);
+        if let Some(maxwidth) = &self.maxwidth {
+            url.query_pairs_mut()
+                .append_pair("maxwidth", maxwidth.to_string().as_str());
+        }
+        if let Some(omitscript) = &self.omitscript {
+            url.query_pairs_mut()
+                .append_pair("omitscript", omitscript.to_string().as_str());
+        }
+        if let Some(hidecaption) = &self.hidecaption {
+            url.query_pairs_mut()
+                .append_pair("hidecaption", hidecaption.to_string().as_str());
+        }
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        let status = response.status();
+        match status {
+            StatusCode::OK => Ok(EndpointRet::Ok(
+                serde_json::from_slice(response.body())
+                    .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+            )),
+            status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+                Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+                Err(_) => Ok(EndpointRet::Other((
+                    status,
+                    Err(response.body().to_owned()),
+                ))),
+            },
+        }
+    }
+}
+
+//
+//
+//
+pub type ReadingResponseBodyOkJson = InstagramOembed;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../tests/response_body_json_files/v14.0/instagram_oembed__reading.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.provider_name, "Instagram");
+                assert_eq!(ok_json.author_name.as_deref(), Some("bkrs_test"));
+                assert_eq!(ok_json.width, Some(658));
+                assert!(ok_json.html.starts_with("<blockquote"));
+                assert!(ok_json.thumbnail_url.is_some());
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = Reading::new(
+            "https://www.instagram.com/p/Cay3CTXLREt/",
+            "APP_ID|CLIENT_TOKEN",
+            None,
+        )
+        .maxwidth(320)
+        .omitscript(true)
+        .hidecaption(true);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/instagram_oembed?url=https%3A%2F%2Fwww.instagram.com%2Fp%2FCay3CTXLREt%2F&maxwidth=320&omitscript=true&hidecaption=true&access_token=APP_ID%7CCLIENT_TOKEN"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Instagram Messaging, the endpoints live under the page and conversation nodes and are
+//! grouped here.
+//!
+//! [Ref](https://developers.facebook.com/docs/messenger-platform/instagram)
+
+pub use super::{
+    conversation::messages::reading::{
+        Reading as ConversationMessagesReading,
+        ReadingResponseBodyOkJson as ConversationMessagesReadingResponseBodyOkJson,
+    },
+    page::{
+        conversations::reading::{
+            Reading as ConversationsReading,
+            ReadingResponseBodyOkJson as ConversationsReadingResponseBodyOkJson,
+        },
+        messages::creating::{
+            CreatingResponseBodyOkJson as MessagesCreatingResponseBodyOkJson,
+            CreatingWithMessage as MessagesCreatingWithMessage,
+            CreatingWithReaction as MessagesCreatingWithReaction,
+            ValueAttachment as MessagesCreatingValueAttachment,
+            ValueButton as MessagesCreatingValueButton,
+            ValueDefaultAction as MessagesCreatingValueDefaultAction,
+            ValueGenericTemplateElement as MessagesCreatingValueGenericTemplateElement,
+            ValueMessage as MessagesCreatingValueMessage,
+            ValueMessageTag as MessagesCreatingValueMessageTag,
+            ValueQuickReply as MessagesCreatingValueQuickReply,
+            ValueTemplate as MessagesCreatingValueTemplate, GENERIC_TEMPLATE_BUTTONS_MAX,
+            GENERIC_TEMPLATE_ELEMENTS_MAX, QUICK_REPLIES_MAX, REACTION_DEFAULT,
+        },
+    },
+};
@@ -1,17 +1,71 @@
 //
+pub mod conversation;
+pub mod debug_token;
 pub mod ig_comment;
 pub mod ig_container;
+pub mod ig_hashtag;
+pub mod ig_hashtag_search;
 pub mod ig_media;
 pub mod ig_user;
+pub mod instagram_oembed;
+pub mod me;
+pub mod messaging;
+pub mod oauth;
+pub mod page;
 
+pub use conversation::messages::reading::{
+    Reading as ConversationMessagesReading,
+    ReadingResponseBodyOkJson as ConversationMessagesReadingResponseBodyOkJson,
+};
+pub use debug_token::reading::{
+    Reading as DebugTokenReading, ReadingResponseBodyOkJson as DebugTokenReadingResponseBodyOkJson,
+};
 pub use ig_comment::{
     deleting::Deleting as IgCommentDeleting,
     reading::{BulkReading as IgCommentBulkReading, Reading as IgCommentReading},
-    replies::creating::Creating as IgCommentRepliesCreating,
+    replies::{
+        creating::Creating as IgCommentRepliesCreating,
+        reading::{
+            Reading as IgCommentRepliesReading,
+            ReadingResponseBodyOkJson as IgCommentRepliesReadingResponseBodyOkJson,
+        },
+    },
     updating::UpdatingWithHideOrUnhide as IgCommentUpdatingWithHideOrUnhide,
 };
-pub use ig_container::reading::Reading as IgContainerReading;
+pub use ig_container::{
+    reading::Reading as IgContainerReading,
+    upload_status::reading::{
+        Reading as IgContainerUploadStatusReading,
+        ReadingResponseBodyOkJson as IgContainerUploadStatusReadingResponseBodyOkJson,
+    },
+    uploading::{
+        Uploading as IgContainerUploading, UploadingDebugInfo as IgContainerUploadingDebugInfo,
+        UploadingResponseBodyOkJson as IgContainerUploadingResponseBodyOkJson,
+        ValueUploadSource as IgContainerUploadingValueUploadSource,
+    },
+};
+pub use ig_hashtag::{
+    recent_media::reading::{
+        Reading as IgHashtagRecentMediaReading,
+        ReadingResponseBodyOkJson as IgHashtagRecentMediaReadingResponseBodyOkJson,
+    },
+    top_media::reading::{
+        Reading as IgHashtagTopMediaReading,
+        ReadingResponseBodyOkJson as IgHashtagTopMediaReadingResponseBodyOkJson,
+    },
+};
+pub use ig_hashtag_search::reading::{
+    ExtInfoError as IgHashtagSearchReadingExtInfoError, Reading as IgHashtagSearchReading,
+};
 pub use ig_media::{
+    children::reading::{
+        Reading as IgMediaChildrenReading,
+        ReadingResponseBodyOkJson as IgMediaChildrenReadingResponseBodyOkJson,
+    },
+    collaborators::reading::{
+        Reading as IgMediaCollaboratorsReading,
+        ReadingResponseBodyOkJson as IgMediaCollaboratorsReadingResponseBodyOkJson,
+    },
     comments::{
         creating::Creating as IgMediaCommentsCreating,
         reading::{
@@ -19,24 +73,143 @@ pub use ig_media::{
             ReadingResponseBodyOkJson as IgMediaCommentsReadingResponseBodyOkJson,
         },
     },
+    insights::reading::{
+        Reading as IgMediaInsightsReading,
+        ReadingResponseBodyOkJson as IgMediaInsightsReadingResponseBodyOkJson,
+    },
+    product_tags::{
+        creating::{
+            Creating as IgMediaProductTagsCreating,
+            CreatingResponseBodyOkJson as IgMediaProductTagsCreatingResponseBodyOkJson,
+        },
+        deleting::{
+            Deleting as IgMediaProductTagsDeleting,
+            DeletingResponseBodyOkJson as IgMediaProductTagsDeletingResponseBodyOkJson,
+        },
+        reading::{
+            Reading as IgMediaProductTagsReading,
+            ReadingResponseBodyOkJson as IgMediaProductTagsReadingResponseBodyOkJson,
+        },
+    },
+    reading::{BulkReading as IgMediaBulkReading, Reading as IgMediaReading},
     updating::UpdatingWithEnableOrDisableComments as IgMediaUpdatingWithEnableOrDisableComments,
 };
 pub use ig_user::{
+    available_catalogs::reading::{
+        Reading as IgUserAvailableCatalogsReading,
+        ReadingResponseBodyOkJson as IgUserAvailableCatalogsReadingResponseBodyOkJson,
+    },
     business_discovery::reading::Reading as IgUserBusinessDiscoveryReading,
+    catalog_product_search::reading::{
+        Reading as IgUserCatalogProductSearchReading,
+        ReadingResponseBodyOkJson as IgUserCatalogProductSearchReadingResponseBodyOkJson,
+    },
+    content_publishing_limit::reading::{
+        Reading as IgUserContentPublishingLimitReading,
+        ReadingResponseBodyOkJson as IgUserContentPublishingLimitReadingResponseBodyOkJson,
+    },
+    insights::reading::{
+        Reading as IgUserInsightsReading,
+        ReadingResponseBodyOkJson as IgUserInsightsReadingResponseBodyOkJson,
+    },
+    live_media::reading::{
+        Reading as IgUserLiveMediaReading,
+        ReadingResponseBodyOkJson as IgUserLiveMediaReadingResponseBodyOkJson,
+    },
     media::creating::{
         CreatingWithCarousel as IgUserMediaCreatingWithCarousel,
         CreatingWithCarouselItemImage as IgUserMediaCreatingWithCarouselItemImage,
         CreatingWithCarouselItemVideo as IgUserMediaCreatingWithCarouselItemVideo,
         CreatingWithImage as IgUserMediaCreatingWithImage,
         CreatingWithReels as IgUserMediaCreatingWithReels,
+        CreatingWithResumableUpload as IgUserMediaCreatingWithResumableUpload,
+        CreatingWithStory as IgUserMediaCreatingWithStory,
         CreatingWithVideo as IgUserMediaCreatingWithVideo,
         ExtInfoError as IgUserMediaCreatingExtInfoError,
+        ValueProductTag as IgUserMediaCreatingValueProductTag,
+        ValueResumableUploadMediaType as IgUserMediaCreatingValueResumableUploadMediaType,
+        ValueStoryMedia as IgUserMediaCreatingValueStoryMedia,
         ValueUserTag as IgUserMediaCreatingValueUserTag,
     },
+    media::reading::{
+        Reading as IgUserMediaReading,
+        ReadingResponseBodyOkJson as IgUserMediaReadingResponseBodyOkJson,
+    },
     media_publish::creating::{
         Creating as IgUserMediaPublishCreating,
         CreatingResponseBodyOkJson as IgUserMediaPublishCreatingResponseBodyOkJson,
     },
+    mentioned_comment::reading::{
+        Reading as IgUserMentionedCommentReading,
+        ReadingResponseBodyOkJson as IgUserMentionedCommentReadingResponseBodyOkJson,
+    },
+    mentioned_media::reading::{
+        Reading as IgUserMentionedMediaReading,
+        ReadingResponseBodyOkJson as IgUserMentionedMediaReadingResponseBodyOkJson,
+    },
+    mentions::creating::{
+        Creating as IgUserMentionsCreating,
+        CreatingResponseBodyOkJson as IgUserMentionsCreatingResponseBodyOkJson,
+    },
+    product_appeal::{
+        creating::{
+            Creating as IgUserProductAppealCreating,
+            CreatingResponseBodyOkJson as IgUserProductAppealCreatingResponseBodyOkJson,
+        },
+        reading::{
+            Reading as IgUserProductAppealReading,
+            ReadingResponseBodyOkJson as IgUserProductAppealReadingResponseBodyOkJson,
+        },
+    },
+    reading::{
+        Reading as IgUserReading, ReadingResponseBodyOkJson as IgUserReadingResponseBodyOkJson,
+    },
+    recently_searched_hashtags::reading::{
+        Reading as IgUserRecentlySearchedHashtagsReading,
+        ReadingResponseBodyOkJson as IgUserRecentlySearchedHashtagsReadingResponseBodyOkJson,
+    },
+    stories::reading::{
+        Reading as IgUserStoriesReading,
+        ReadingResponseBodyOkJson as IgUserStoriesReadingResponseBodyOkJson,
+    },
+    tags::reading::{
+        Reading as IgUserTagsReading,
+        ReadingResponseBodyOkJson as IgUserTagsReadingResponseBodyOkJson,
+    },
+};
+pub use instagram_oembed::reading::{
+    Reading as InstagramOembedReading,
+    ReadingResponseBodyOkJson as InstagramOembedReadingResponseBodyOkJson,
+};
+pub use me::accounts::reading::{
+    Reading as MeAccountsReading, ReadingResponseBodyOkJson as MeAccountsReadingResponseBodyOkJson,
+};
+pub use oauth::access_token::reading::{
+    ReadingResponseBodyOkJson as OauthAccessTokenReadingResponseBodyOkJson,
+    ReadingWithClientCredentials as OauthAccessTokenReadingWithClientCredentials,
+    ReadingWithFbExchangeToken as OauthAccessTokenReadingWithFbExchangeToken,
+};
+pub use page::{
+    access_token::reading::{
+        Reading as PageAccessTokenReading,
+        ReadingResponseBodyOkJson as PageAccessTokenReadingResponseBodyOkJson,
+    },
+    conversations::reading::{
+        Reading as PageConversationsReading,
+        ReadingResponseBodyOkJson as PageConversationsReadingResponseBodyOkJson,
+    },
+    messages::creating::{
+        CreatingResponseBodyOkJson as PageMessagesCreatingResponseBodyOkJson,
+        CreatingWithMessage as PageMessagesCreatingWithMessage,
+        CreatingWithReaction as PageMessagesCreatingWithReaction,
+        ValueAttachment as PageMessagesCreatingValueAttachment,
+        ValueButton as PageMessagesCreatingValueButton,
+        ValueGenericTemplateElement as PageMessagesCreatingValueGenericTemplateElement,
+        ValueMessage as PageMessagesCreatingValueMessage,
+        ValueMessageTag as PageMessagesCreatingValueMessageTag,
+        ValueQuickReply as PageMessagesCreatingValueQuickReply,
+    },
+    reading::{Reading as PageReading, ReadingResponseBodyOkJson as PageReadingResponseBodyOkJson},
 };
 
 //
@@ -47,3 +220,4 @@ pub use common::{EndpointError, EndpointRet};
 //
 pub const URL_BASE: &str = "https://graph.facebook.com";
 pub const VERSION: &str = "v15.0";
+pub const RUPLOAD_URL_BASE: &str = "https://rupload.facebook.com";
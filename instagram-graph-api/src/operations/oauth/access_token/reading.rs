@@ -0,0 +1,253 @@
+//! [Ref](https://developers.facebook.com/docs/facebook-login/guides/access-tokens/get-long-lived)
+
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::{AppAccessToken, ExchangeableAccessToken, UserAccessToken},
+};
+
+// Short-lived user access token to long-lived user access token
+#[derive(Debug, Clone)]
+pub struct ReadingWithFbExchangeToken {
+    pub app_id: u64,
+    pub app_secret: Box<str>,
+    pub fb_exchange_token: Box<str>,
+    //
+    pub version: Option<Box<str>>,
+}
+
+impl ReadingWithFbExchangeToken {
+    pub fn new(
+        app_id: u64,
+        app_secret: impl AsRef<str>,
+        fb_exchange_token: impl ExchangeableAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            app_id,
+            app_secret: app_secret.as_ref().into(),
+            fb_exchange_token: fb_exchange_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for ReadingWithFbExchangeToken {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/oauth/access_token",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("grant_type", "fb_exchange_token")
+            .append_pair("client_id", self.app_id.to_string().as_str())
+            .append_pair("client_secret", &self.app_secret)
+            .append_pair("fb_exchange_token", &self.fb_exchange_token);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        parse_response(response)
+    }
+}
+
+// App access token
+#[derive(Debug, Clone)]
+pub struct ReadingWithClientCredentials {
+    pub app_id: u64,
+    pub app_secret: Box<str>,
+    //
+    pub version: Option<Box<str>>,
+}
+
+impl ReadingWithClientCredentials {
+    pub fn new(
+        app_id: u64,
+        app_secret: impl AsRef<str>,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            app_id,
+            app_secret: app_secret.as_ref().into(),
+            version: version.into(),
+        }
+    }
+}
+
+impl Endpoint for ReadingWithClientCredentials {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<ReadingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/oauth/access_token",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("grant_type", "client_credentials")
+            .append_pair("client_id", self.app_id.to_string().as_str())
+            .append_pair("client_secret", &self.app_secret);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        parse_response(response)
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadingResponseBodyOkJson {
+    pub access_token: Box<str>,
+    pub token_type: Option<Box<str>>,
+    // Seconds, not set for app access tokens
+    pub expires_in: Option<u64>,
+}
+
+impl ReadingResponseBodyOkJson {
+    // For ReadingWithFbExchangeToken
+    pub fn user_access_token(&self) -> UserAccessToken {
+        UserAccessToken::from(&self.access_token)
+    }
+
+    // For ReadingWithClientCredentials
+    pub fn app_access_token(&self) -> AppAccessToken {
+        AppAccessToken::from(&self.access_token)
+    }
+}
+
+//
+//
+//
+fn parse_response(
+    response: Response<Body>,
+) -> Result<EndpointRet<ReadingResponseBodyOkJson>, EndpointError> {
+    let status = response.status();
+    match status {
+        StatusCode::OK => Ok(EndpointRet::Ok(
+            serde_json::from_slice(response.body())
+                .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+        )),
+        status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+            Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+            Err(_) => Ok(EndpointRet::Other((
+                status,
+                Err(response.body().to_owned()),
+            ))),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/oauth__access_token__reading__fb_exchange_token.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert!(ok_json.access_token.starts_with("EAAB"));
+                assert_eq!(ok_json.token_type.as_deref(), Some("bearer"));
+                assert_eq!(ok_json.expires_in, Some(5183944));
+                assert_eq!(
+                    ok_json.user_access_token().as_ref(),
+                    ok_json.access_token.as_ref()
+                );
+            }
+            Err(err) => panic!("{}", err),
+        }
+
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/oauth__access_token__reading__client_credentials.json"
+        );
+        match serde_json::from_str::<ReadingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert!(ok_json.access_token.starts_with("138483919580948|"));
+                assert_eq!(ok_json.expires_in, None);
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        let ep = ReadingWithFbExchangeToken::new(1, "APP_SECRET", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/oauth/access_token?grant_type=fb_exchange_token&client_id=1&client_secret=APP_SECRET&fb_exchange_token=ACCESS_TOKEN"
+        );
+
+        let access_token = UserAccessToken::from("USER_ACCESS_TOKEN");
+        let ep = ReadingWithFbExchangeToken::new(1, "APP_SECRET", &access_token, None);
+        assert_eq!(ep.fb_exchange_token.as_ref(), "USER_ACCESS_TOKEN");
+
+        let ep = ReadingWithClientCredentials::new(1, "APP_SECRET", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::GET);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/oauth/access_token?grant_type=client_credentials&client_id=1&client_secret=APP_SECRET"
+        );
+    }
+}
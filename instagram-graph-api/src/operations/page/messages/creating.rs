@@ -0,0 +1,582 @@
+use http_api_client_endpoint::{
+    http::{
+        header::{ACCEPT, USER_AGENT},
+        Method, StatusCode,
+    },
+    Body, Endpoint, Request, Response, MIME_APPLICATION_JSON,
+};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+use url::Url;
+
+use crate::{
+    objects::ResponseBodyErrJson,
+    operations::{
+        common::{EndpointError, EndpointRet},
+        URL_BASE, VERSION,
+    },
+    types::PageLevelAccessToken,
+};
+
+//
+#[derive(Debug, Clone)]
+pub struct CreatingWithMessage {
+    pub page_id: u64,
+    // IGSID
+    pub recipient_id: u64,
+    pub message: ValueMessage,
+    pub tag: Option<ValueMessageTag>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl CreatingWithMessage {
+    pub fn new(
+        page_id: u64,
+        recipient_id: u64,
+        message: ValueMessage,
+        access_token: impl PageLevelAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            page_id,
+            recipient_id,
+            message,
+            tag: None,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn tag(mut self, value: ValueMessageTag) -> Self {
+        self.tag = Some(value);
+        self
+    }
+}
+
+impl Endpoint for CreatingWithMessage {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<CreatingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/messages",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.page_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        if let Some(quick_replies) = &self.message.quick_replies {
+            if quick_replies.len() > QUICK_REPLIES_MAX {
+                return Err(EndpointError::Other(
+                    format!("quick_replies max {QUICK_REPLIES_MAX}").into(),
+                ));
+            }
+        }
+        if let Some(ValueAttachment::Template(ValueTemplate::Generic { elements })) =
+            &self.message.attachment
+        {
+            if elements.len() > GENERIC_TEMPLATE_ELEMENTS_MAX {
+                return Err(EndpointError::Other(
+                    format!("generic template elements max {GENERIC_TEMPLATE_ELEMENTS_MAX}").into(),
+                ));
+            }
+            if elements.iter().any(|element| {
+                element
+                    .buttons
+                    .as_ref()
+                    .map(|buttons| buttons.len() > GENERIC_TEMPLATE_BUTTONS_MAX)
+                    .unwrap_or(false)
+            }) {
+                return Err(EndpointError::Other(
+                    format!("generic template buttons max {GENERIC_TEMPLATE_BUTTONS_MAX}").into(),
+                ));
+            }
+        }
+
+        url.query_pairs_mut()
+            .append_pair("recipient", recipient(self.recipient_id).as_ref());
+        url.query_pairs_mut().append_pair(
+            "message",
+            serde_json::to_string(&self.message)
+                .map_err(|_| EndpointError::Other("ser message failed".into()))?
+                .as_ref(),
+        );
+        if let Some(tag) = &self.tag {
+            url.query_pairs_mut()
+                .append_pair("messaging_type", "MESSAGE_TAG");
+            url.query_pairs_mut().append_pair("tag", tag.as_str());
+        }
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        parse_response(response)
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub struct CreatingWithReaction {
+    pub page_id: u64,
+    // IGSID
+    pub recipient_id: u64,
+    pub message_id: Box<str>,
+    // None to remove the reaction
+    pub reaction: Option<Box<str>>,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl CreatingWithReaction {
+    pub fn new(
+        page_id: u64,
+        recipient_id: u64,
+        message_id: impl AsRef<str>,
+        access_token: impl PageLevelAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            page_id,
+            recipient_id,
+            message_id: message_id.as_ref().into(),
+            reaction: Some(REACTION_DEFAULT.into()),
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    pub fn reaction(mut self, value: impl AsRef<str>) -> Self {
+        self.reaction = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn unreact(mut self) -> Self {
+        self.reaction = None;
+        self
+    }
+}
+
+impl Endpoint for CreatingWithReaction {
+    type RenderRequestError = EndpointError;
+
+    type ParseResponseOutput = EndpointRet<CreatingResponseBodyOkJson>;
+    type ParseResponseError = EndpointError;
+
+    fn render_request(&self) -> Result<Request<Body>, Self::RenderRequestError> {
+        let url = format!(
+            "{}/{}/{}/messages",
+            URL_BASE,
+            self.version.as_deref().unwrap_or(VERSION),
+            self.page_id,
+        );
+        let mut url = Url::parse(&url).map_err(EndpointError::MakeRequestUrlFailed)?;
+
+        url.query_pairs_mut()
+            .append_pair("recipient", recipient(self.recipient_id).as_ref());
+        let payload = match &self.reaction {
+            Some(reaction) => {
+                url.query_pairs_mut().append_pair("sender_action", "react");
+                serde_json::json!({ "message_id": self.message_id, "reaction": reaction })
+            }
+            None => {
+                url.query_pairs_mut()
+                    .append_pair("sender_action", "unreact");
+                serde_json::json!({ "message_id": self.message_id })
+            }
+        };
+        url.query_pairs_mut()
+            .append_pair("payload", payload.to_string().as_ref());
+        url.query_pairs_mut()
+            .append_pair("access_token", &self.access_token);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(USER_AGENT, "instagram-graph-api")
+            .header(ACCEPT, MIME_APPLICATION_JSON)
+            .body(vec![])
+            .map_err(EndpointError::MakeRequestFailed)?;
+
+        Ok(request)
+    }
+
+    fn parse_response(
+        &self,
+        response: Response<Body>,
+    ) -> Result<Self::ParseResponseOutput, Self::ParseResponseError> {
+        parse_response(response)
+    }
+}
+
+//
+//
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CreatingResponseBodyOkJson {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub recipient_id: u64,
+    // Not returned for sender actions
+    pub message_id: Option<Box<str>>,
+}
+
+fn parse_response(
+    response: Response<Body>,
+) -> Result<EndpointRet<CreatingResponseBodyOkJson>, EndpointError> {
+    let status = response.status();
+    match status {
+        StatusCode::OK => Ok(EndpointRet::Ok(
+            serde_json::from_slice(response.body())
+                .map_err(EndpointError::DeResponseBodyOkJsonFailed)?,
+        )),
+        status => match serde_json::from_slice::<ResponseBodyErrJson>(response.body()) {
+            Ok(err_json) => Ok(EndpointRet::Other((status, Ok(err_json)))),
+            Err(_) => Ok(EndpointRet::Other((
+                status,
+                Err(response.body().to_owned()),
+            ))),
+        },
+    }
+}
+
+fn recipient(recipient_id: u64) -> String {
+    serde_json::json!({ "id": recipient_id.to_string() }).to_string()
+}
+
+//
+pub const QUICK_REPLIES_MAX: usize = 13;
+pub const GENERIC_TEMPLATE_ELEMENTS_MAX: usize = 10;
+// Per element
+pub const GENERIC_TEMPLATE_BUTTONS_MAX: usize = 3;
+pub const REACTION_DEFAULT: &str = "love";
+
+//
+#[derive(Serialize, Debug, Clone)]
+pub struct ValueMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<ValueAttachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_replies: Option<Vec<ValueQuickReply>>,
+}
+impl ValueMessage {
+    pub fn text(value: impl AsRef<str>) -> Self {
+        Self {
+            text: Some(value.as_ref().into()),
+            attachment: None,
+            quick_replies: None,
+        }
+    }
+
+    pub fn attachment(value: ValueAttachment) -> Self {
+        Self {
+            text: None,
+            attachment: Some(value),
+            quick_replies: None,
+        }
+    }
+
+    pub fn generic_template(elements: Vec<ValueGenericTemplateElement>) -> Self {
+        Self::attachment(ValueAttachment::Template(ValueTemplate::Generic {
+            elements,
+        }))
+    }
+
+    pub fn quick_replies(mut self, value: Vec<ValueQuickReply>) -> Self {
+        self.quick_replies = Some(value);
+        self
+    }
+}
+
+//
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", content = "payload", rename_all = "snake_case")]
+pub enum ValueAttachment {
+    Image { url: Box<str> },
+    Video { url: Box<str> },
+    Audio { url: Box<str> },
+    File { url: Box<str> },
+    Template(ValueTemplate),
+}
+impl ValueAttachment {
+    pub fn image(url: impl AsRef<str>) -> Self {
+        Self::Image {
+            url: url.as_ref().into(),
+        }
+    }
+
+    pub fn video(url: impl AsRef<str>) -> Self {
+        Self::Video {
+            url: url.as_ref().into(),
+        }
+    }
+
+    pub fn audio(url: impl AsRef<str>) -> Self {
+        Self::Audio {
+            url: url.as_ref().into(),
+        }
+    }
+
+    pub fn file(url: impl AsRef<str>) -> Self {
+        Self::File {
+            url: url.as_ref().into(),
+        }
+    }
+}
+
+//
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "template_type", rename_all = "snake_case")]
+pub enum ValueTemplate {
+    Generic {
+        elements: Vec<ValueGenericTemplateElement>,
+    },
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ValueGenericTemplateElement {
+    pub title: Box<str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_action: Option<ValueDefaultAction>,
+    // Max 3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buttons: Option<Vec<ValueButton>>,
+}
+impl ValueGenericTemplateElement {
+    pub fn new(title: impl AsRef<str>) -> Self {
+        Self {
+            title: title.as_ref().into(),
+            subtitle: None,
+            image_url: None,
+            default_action: None,
+            buttons: None,
+        }
+    }
+
+    pub fn subtitle(mut self, value: impl AsRef<str>) -> Self {
+        self.subtitle = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn image_url(mut self, value: impl AsRef<str>) -> Self {
+        self.image_url = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn default_action_url(mut self, value: impl AsRef<str>) -> Self {
+        self.default_action = Some(ValueDefaultAction::WebUrl {
+            url: value.as_ref().into(),
+        });
+        self
+    }
+
+    pub fn buttons(mut self, value: Vec<ValueButton>) -> Self {
+        self.buttons = Some(value);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ValueDefaultAction {
+    WebUrl { url: Box<str> },
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ValueButton {
+    WebUrl { url: Box<str>, title: Box<str> },
+    Postback { title: Box<str>, payload: Box<str> },
+}
+impl ValueButton {
+    pub fn web_url(url: impl AsRef<str>, title: impl AsRef<str>) -> Self {
+        Self::WebUrl {
+            url: url.as_ref().into(),
+            title: title.as_ref().into(),
+        }
+    }
+
+    pub fn postback(title: impl AsRef<str>, payload: impl AsRef<str>) -> Self {
+        Self::Postback {
+            title: title.as_ref().into(),
+            payload: payload.as_ref().into(),
+        }
+    }
+}
+
+//
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "content_type", rename_all = "snake_case")]
+pub enum ValueQuickReply {
+    Text { title: Box<str>, payload: Box<str> },
+}
+impl ValueQuickReply {
+    pub fn text(title: impl AsRef<str>, payload: impl AsRef<str>) -> Self {
+        Self::Text {
+            title: title.as_ref().into(),
+            payload: payload.as_ref().into(),
+        }
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub enum ValueMessageTag {
+    // Allows a human agent to reply within 7 days of the user's last message
+    HumanAgent,
+}
+impl ValueMessageTag {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HumanAgent => "HUMAN_AGENT",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_response_body_ok_json() {
+        //
+        let content = include_str!(
+            "../../../../tests/response_body_json_files/v14.0/page_0__messages__creating_sample.json"
+        );
+        match serde_json::from_str::<CreatingResponseBodyOkJson>(content) {
+            Ok(ok_json) => {
+                // println!("{:?}", ok_json);
+                assert_eq!(ok_json.recipient_id, 5614520785338163);
+                assert!(ok_json.message_id.is_some());
+            }
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request() {
+        //
+        let ep = CreatingWithMessage::new(
+            1,
+            2,
+            ValueMessage::text("Hi").quick_replies(vec![ValueQuickReply::text("Yes", "YES")]),
+            "ACCESS_TOKEN",
+            None,
+        )
+        .tag(ValueMessageTag::HumanAgent);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/messages?recipient=%7B%22id%22%3A%222%22%7D&message=%7B%22text%22%3A%22Hi%22%2C%22quick_replies%22%3A%5B%7B%22content_type%22%3A%22text%22%2C%22title%22%3A%22Yes%22%2C%22payload%22%3A%22YES%22%7D%5D%7D&messaging_type=MESSAGE_TAG&tag=HUMAN_AGENT&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithMessage::new(
+            1,
+            2,
+            ValueMessage::attachment(ValueAttachment::image("https://example.com/1.jpg")),
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/messages?recipient=%7B%22id%22%3A%222%22%7D&message=%7B%22attachment%22%3A%7B%22type%22%3A%22image%22%2C%22payload%22%3A%7B%22url%22%3A%22https%3A%2F%2Fexample.com%2F1.jpg%22%7D%7D%7D&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithMessage::new(
+            1,
+            2,
+            ValueMessage::generic_template(vec![ValueGenericTemplateElement::new("Foo")
+                .default_action_url("https://example.com")
+                .buttons(vec![ValueButton::postback("Bar", "BAR")])]),
+            "ACCESS_TOKEN",
+            None,
+        );
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/messages?recipient=%7B%22id%22%3A%222%22%7D&message=%7B%22attachment%22%3A%7B%22type%22%3A%22template%22%2C%22payload%22%3A%7B%22template_type%22%3A%22generic%22%2C%22elements%22%3A%5B%7B%22title%22%3A%22Foo%22%2C%22default_action%22%3A%7B%22type%22%3A%22web_url%22%2C%22url%22%3A%22https%3A%2F%2Fexample.com%22%7D%2C%22buttons%22%3A%5B%7B%22type%22%3A%22postback%22%2C%22title%22%3A%22Bar%22%2C%22payload%22%3A%22BAR%22%7D%5D%7D%5D%7D%7D%7D&access_token=ACCESS_TOKEN"
+        );
+
+        //
+        let ep = CreatingWithMessage::new(
+            1,
+            2,
+            ValueMessage::text("Hi").quick_replies(
+                (0..14)
+                    .map(|i| ValueQuickReply::text(i.to_string(), i.to_string()))
+                    .collect(),
+            ),
+            "ACCESS_TOKEN",
+            None,
+        );
+        match ep.render_request() {
+            Err(EndpointError::Other(err)) => assert_eq!(err.to_string(), "quick_replies max 13"),
+            x => panic!("{x:?}"),
+        }
+
+        //
+        let ep = CreatingWithMessage::new(
+            1,
+            2,
+            ValueMessage::generic_template(vec![ValueGenericTemplateElement::new("Foo").buttons(
+                (0..4)
+                    .map(|i| ValueButton::postback(i.to_string(), i.to_string()))
+                    .collect(),
+            )]),
+            "ACCESS_TOKEN",
+            None,
+        );
+        match ep.render_request() {
+            Err(EndpointError::Other(err)) => {
+                assert_eq!(err.to_string(), "generic template buttons max 3")
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_render_request_with_reaction() {
+        let ep = CreatingWithReaction::new(1, 2, "MID", "ACCESS_TOKEN", None);
+        let req = ep.render_request().unwrap();
+        assert_eq!(req.method(), Method::POST);
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/messages?recipient=%7B%22id%22%3A%222%22%7D&sender_action=react&payload=%7B%22message_id%22%3A%22MID%22%2C%22reaction%22%3A%22love%22%7D&access_token=ACCESS_TOKEN"
+        );
+
+        let ep = CreatingWithReaction::new(1, 2, "MID", "ACCESS_TOKEN", None).unreact();
+        let req = ep.render_request().unwrap();
+        assert_eq!(
+            req.uri().path_and_query().unwrap(),
+            "/v15.0/1/messages?recipient=%7B%22id%22%3A%222%22%7D&sender_action=unreact&payload=%7B%22message_id%22%3A%22MID%22%7D&access_token=ACCESS_TOKEN"
+        );
+    }
+}
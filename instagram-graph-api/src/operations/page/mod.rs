@@ -0,0 +1,5 @@
+//
+pub mod access_token;
+pub mod conversations;
+pub mod messages;
+pub mod reading;
@@ -0,0 +1,114 @@
+//! [Ref](https://developers.facebook.com/docs/facebook-login/guides/access-tokens)
+
+//
+wrapping_macro::wrapping_string! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct UserAccessToken(String);
+}
+
+wrapping_macro::wrapping_string! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PageAccessToken(String);
+}
+
+// Format is {app-id}|{app-secret} or as returned by oauth/access_token with client_credentials
+wrapping_macro::wrapping_string! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AppAccessToken(String);
+}
+
+impl AppAccessToken {
+    pub fn from_app_id_and_secret(app_id: u64, app_secret: impl AsRef<str>) -> Self {
+        Self(format!("{app_id}|{}", app_secret.as_ref()))
+    }
+}
+
+wrapping_macro::wrapping_string! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SystemUserAccessToken(String);
+}
+
+//
+// Token kinds an operation accepts.
+// Raw strings implement all of them, they are not checked.
+//
+
+// IG User, IG Media, IG Comment, IG Hashtag, IG Container and Page operations
+pub trait IgAccessToken: AsRef<str> {}
+
+// me and Page access token operations
+pub trait UserLevelAccessToken: AsRef<str> {}
+
+// Messaging operations
+pub trait PageLevelAccessToken: AsRef<str> {}
+
+// instagram_oembed operations
+pub trait AppLevelAccessToken: AsRef<str> {}
+
+// debug_token operations, the user access token must be of an app developer
+pub trait DebugTokenAccessToken: AsRef<str> {}
+
+// oauth/access_token fb_exchange_token, system user access tokens can not be exchanged
+pub trait ExchangeableAccessToken: AsRef<str> {}
+
+macro_rules! impl_as_ref_str {
+    ($($name:ident),*) => {
+        $(
+            impl AsRef<str> for $name {
+                fn as_ref(&self) -> &str {
+                    self.inner().as_str()
+                }
+            }
+        )*
+    };
+}
+impl_as_ref_str!(
+    UserAccessToken,
+    PageAccessToken,
+    AppAccessToken,
+    SystemUserAccessToken
+);
+
+macro_rules! impl_kind {
+    ($kind:ident for $($name:ty),*) => {
+        $(
+            impl $kind for $name {}
+        )*
+        impl<T: $kind + ?Sized> $kind for &T {}
+    };
+}
+impl_kind!(IgAccessToken for UserAccessToken, PageAccessToken, SystemUserAccessToken, str, String, Box<str>);
+impl_kind!(UserLevelAccessToken for UserAccessToken, SystemUserAccessToken, str, String, Box<str>);
+impl_kind!(PageLevelAccessToken for PageAccessToken, str, String, Box<str>);
+impl_kind!(AppLevelAccessToken for AppAccessToken, str, String, Box<str>);
+impl_kind!(DebugTokenAccessToken for AppAccessToken, UserAccessToken, str, String, Box<str>);
+impl_kind!(ExchangeableAccessToken for UserAccessToken, str, String, Box<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ig(access_token: impl IgAccessToken) -> Box<str> {
+        access_token.as_ref().into()
+    }
+
+    fn app_level(access_token: impl AppLevelAccessToken) -> Box<str> {
+        access_token.as_ref().into()
+    }
+
+    #[test]
+    fn test_kinds() {
+        assert_eq!(ig(UserAccessToken::from("U")).as_ref(), "U");
+        let page_access_token = PageAccessToken::from("P");
+        assert_eq!(ig(&page_access_token).as_ref(), "P");
+        assert_eq!(ig(SystemUserAccessToken::from("S")).as_ref(), "S");
+        assert_eq!(ig("RAW").as_ref(), "RAW");
+        let raw = Box::<str>::from("RAW");
+        assert_eq!(ig(&raw).as_ref(), "RAW");
+
+        assert_eq!(
+            app_level(AppAccessToken::from_app_id_and_secret(1, "SECRET")).as_ref(),
+            "1|SECRET"
+        );
+    }
+}
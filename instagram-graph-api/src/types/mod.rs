@@ -0,0 +1,8 @@
+//
+pub mod access_token;
+
+pub use access_token::{
+    AppAccessToken, AppLevelAccessToken, DebugTokenAccessToken, ExchangeableAccessToken,
+    IgAccessToken, PageAccessToken, PageLevelAccessToken, SystemUserAccessToken, UserAccessToken,
+    UserLevelAccessToken,
+};
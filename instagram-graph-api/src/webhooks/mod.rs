@@ -0,0 +1,12 @@
+//
+pub mod notification;
+pub mod signature;
+pub mod verification;
+
+pub use notification::{
+    CommentEvent, MentionEvent, MessageEvent, MessageReactionAction, MessageReactionEvent,
+    MessagingPostbackEvent, Notification, NotificationChange, NotificationEntry, NotificationError,
+    NotificationMessaging, StoryInsightsEvent,
+};
+pub use signature::{verify_signature, SignatureError};
+pub use verification::{respond_subscription, verify_subscription, VerificationError};
@@ -0,0 +1,407 @@
+//! [Ref](https://developers.facebook.com/docs/instagram-api/guides/webhooks)
+
+use http_api_client_endpoint::{http::Method, Request};
+use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::{
+    deserialize_number_from_string, deserialize_option_number_from_string,
+};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_json::{Error as SerdeJsonError, Value};
+
+use crate::{
+    objects::ig_comment::{IgCommentFrom, IgCommentMedia},
+    webhooks::signature::{verify_signature, SignatureError},
+};
+
+//
+#[derive(Deserialize, Debug, Clone)]
+pub struct Notification {
+    // "instagram" for both IG User and messaging notifications
+    pub object: Box<str>,
+    pub entry: Vec<NotificationEntry>,
+}
+
+impl Notification {
+    // Verify the X-Hub-Signature-256 header, then deserialize the body
+    pub fn from_request(
+        req: &Request<Vec<u8>>,
+        app_secret: impl AsRef<str>,
+    ) -> Result<Self, NotificationError> {
+        if req.method() != Method::POST {
+            return Err(NotificationError::MethodMismatch);
+        }
+        verify_signature(req, app_secret).map_err(NotificationError::SignatureInvalid)?;
+
+        serde_json::from_slice(req.body()).map_err(NotificationError::DeBodyFailed)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NotificationEntry {
+    // IG User id
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    // Seconds for changes, milliseconds for messaging
+    pub time: u64,
+    #[serde(default)]
+    pub changes: Vec<NotificationChange>,
+    #[serde(default)]
+    pub messaging: Vec<NotificationMessaging>,
+}
+
+//
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "NotificationChangeRaw")]
+pub enum NotificationChange {
+    Comments(CommentEvent),
+    LiveComments(CommentEvent),
+    Mentions(MentionEvent),
+    StoryInsights(StoryInsightsEvent),
+    Other {
+        field: Box<str>,
+        value: Value,
+    },
+    // Known field whose value does not match the typed event,
+    // kept so the other changes of the delivery are not lost
+    Malformed {
+        field: Box<str>,
+        value: Value,
+        error: Box<str>,
+    },
+}
+
+#[derive(Deserialize)]
+struct NotificationChangeRaw {
+    field: Box<str>,
+    value: Value,
+}
+
+impl From<NotificationChangeRaw> for NotificationChange {
+    fn from(raw: NotificationChangeRaw) -> Self {
+        let ret = match raw.field.as_ref() {
+            "comments" => CommentEvent::deserialize(&raw.value).map(Self::Comments),
+            "live_comments" => CommentEvent::deserialize(&raw.value).map(Self::LiveComments),
+            "mentions" => MentionEvent::deserialize(&raw.value).map(Self::Mentions),
+            "story_insights" => {
+                StoryInsightsEvent::deserialize(&raw.value).map(Self::StoryInsights)
+            }
+            _ => {
+                return Self::Other {
+                    field: raw.field,
+                    value: raw.value,
+                }
+            }
+        };
+
+        ret.unwrap_or_else(|err| Self::Malformed {
+            field: raw.field,
+            value: raw.value,
+            error: err.to_string().into(),
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentEvent {
+    // IG Comment id
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub from: IgCommentFrom,
+    pub media: IgCommentMedia,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub parent_id: Option<u64>,
+    #[serde(default)]
+    pub text: Box<str>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MentionEvent {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub media_id: u64,
+    // Not set if mentioned in a caption
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub comment_id: Option<u64>,
+}
+
+// Sent once the story expires
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StoryInsightsEvent {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub media_id: u64,
+    #[serde(default)]
+    pub exits: u64,
+    #[serde(default)]
+    pub replies: u64,
+    #[serde(default)]
+    pub reach: u64,
+    #[serde(default)]
+    pub taps_forward: u64,
+    #[serde(default)]
+    pub taps_back: u64,
+    #[serde(default)]
+    pub impressions: u64,
+}
+
+//
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NotificationMessaging {
+    pub sender: MessagingParticipant,
+    pub recipient: MessagingParticipant,
+    // Milliseconds
+    pub timestamp: u64,
+    // messages
+    pub message: Option<MessageEvent>,
+    // messaging_postbacks
+    pub postback: Option<MessagingPostbackEvent>,
+    // message_reactions
+    pub reaction: Option<MessageReactionEvent>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessagingParticipant {
+    // IGSID, or IG User id of the business account itself
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEvent {
+    pub mid: Box<str>,
+    pub text: Option<String>,
+    #[serde(default)]
+    pub attachments: Vec<MessageEventAttachment>,
+    pub quick_reply: Option<MessageEventQuickReply>,
+    pub reply_to: Option<MessageEventReplyTo>,
+    // Sent by the business account itself
+    #[serde(default)]
+    pub is_echo: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+    #[serde(default)]
+    pub is_unsupported: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEventAttachment {
+    // e.g. image, video, audio, file, share, story_mention
+    pub r#type: Box<str>,
+    pub payload: MessageEventAttachmentPayload,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEventAttachmentPayload {
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEventQuickReply {
+    pub payload: Box<str>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEventReplyTo {
+    pub mid: Option<Box<str>>,
+    pub story: Option<MessageEventReplyToStory>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageEventReplyToStory {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u64,
+    pub url: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessagingPostbackEvent {
+    pub mid: Box<str>,
+    pub title: Option<String>,
+    pub payload: Box<str>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessageReactionEvent {
+    pub mid: Box<str>,
+    pub action: MessageReactionAction,
+    // Not set on unreact
+    pub reaction: Option<Box<str>>,
+    pub emoji: Option<String>,
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageReactionAction {
+    React,
+    Unreact,
+    #[serde(other)]
+    Other(String),
+}
+
+//
+#[derive(Debug)]
+pub enum NotificationError {
+    MethodMismatch,
+    SignatureInvalid(SignatureError),
+    DeBodyFailed(SerdeJsonError),
+}
+
+impl core::fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for NotificationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::webhooks::signature::tests::signed_request;
+
+    #[test]
+    fn test_de_comments() {
+        let content = include_str!("../../tests/webhook_json_files/comments.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        // println!("{:?}", notification);
+        assert_eq!(notification.entry[0].id, 17841406427775093);
+        match &notification.entry[0].changes[0] {
+            NotificationChange::Comments(event) => {
+                assert_eq!(event.id, 17865799348089039);
+                assert_eq!(event.from.username.as_ref(), "lunathepuppy85");
+                assert_eq!(event.media.id, 17887498072083520);
+                assert_eq!(event.parent_id, None);
+                assert_eq!(event.text.as_ref(), "This is an example.");
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_live_comments() {
+        let content = include_str!("../../tests/webhook_json_files/live_comments.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        match &notification.entry[0].changes[0] {
+            NotificationChange::LiveComments(event) => {
+                assert_eq!(event.media.media_product_type.as_deref(), Some("LIVE"));
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_mentions() {
+        let content = include_str!("../../tests/webhook_json_files/mentions.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        match &notification.entry[0].changes[0] {
+            NotificationChange::Mentions(event) => {
+                assert_eq!(event.media_id, 17887498072083520);
+                assert_eq!(event.comment_id, Some(17894227972186120));
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_story_insights() {
+        let content = include_str!("../../tests/webhook_json_files/story_insights.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        match &notification.entry[0].changes[0] {
+            NotificationChange::StoryInsights(event) => {
+                assert_eq!(event.media_id, 17887498072083520);
+                assert_eq!(event.reach, 44);
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_messages() {
+        let content = include_str!("../../tests/webhook_json_files/messages.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        let messaging = &notification.entry[0].messaging[0];
+        assert_eq!(messaging.sender.id, 5614520785338163);
+        assert_eq!(messaging.recipient.id, 17841406427775093);
+        let message = messaging.message.as_ref().unwrap();
+        assert_eq!(message.text.as_deref(), Some("Hi"));
+        assert_eq!(message.attachments[0].r#type.as_ref(), "image");
+        assert!(!message.is_echo);
+    }
+
+    #[test]
+    fn test_de_messaging_postbacks() {
+        let content = include_str!("../../tests/webhook_json_files/messaging_postbacks.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        let postback = notification.entry[0].messaging[0]
+            .postback
+            .as_ref()
+            .unwrap();
+        assert_eq!(postback.payload.as_ref(), "BAR");
+    }
+
+    #[test]
+    fn test_de_message_reactions() {
+        let content = include_str!("../../tests/webhook_json_files/message_reactions.json");
+        let notification = serde_json::from_str::<Notification>(content).unwrap();
+        let reaction = notification.entry[0].messaging[0]
+            .reaction
+            .as_ref()
+            .unwrap();
+        assert_eq!(reaction.action, MessageReactionAction::React);
+        assert_eq!(reaction.reaction.as_deref(), Some("love"));
+    }
+
+    #[test]
+    fn test_de_other_field() {
+        let notification = serde_json::from_str::<Notification>(
+            r#"{"object":"instagram","entry":[{"id":"1","time":1,"changes":[{"field":"foo","value":{"bar":1}}]}]}"#,
+        )
+        .unwrap();
+        match &notification.entry[0].changes[0] {
+            NotificationChange::Other { field, .. } => assert_eq!(field.as_ref(), "foo"),
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_de_malformed_change() {
+        let notification = serde_json::from_str::<Notification>(
+            r#"{"object":"instagram","entry":[{"id":"1","time":1,"changes":[{"field":"mentions","value":{"comment_id":"2"}},{"field":"mentions","value":{"media_id":"3"}}]}]}"#,
+        )
+        .unwrap();
+        let changes = &notification.entry[0].changes;
+        assert_eq!(changes.len(), 2);
+        match &changes[0] {
+            NotificationChange::Malformed {
+                field,
+                value,
+                error,
+            } => {
+                assert_eq!(field.as_ref(), "mentions");
+                assert_eq!(value["comment_id"], "2");
+                assert!(error.contains("media_id"));
+            }
+            x => panic!("{x:?}"),
+        }
+        match &changes[1] {
+            NotificationChange::Mentions(event) => {
+                assert_eq!(event.media_id, 3);
+                assert_eq!(event.comment_id, None);
+            }
+            x => panic!("{x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_request() {
+        let content = include_str!("../../tests/webhook_json_files/mentions.json");
+
+        let req = signed_request(content.as_bytes(), "APP_SECRET");
+        let notification = Notification::from_request(&req, "APP_SECRET").unwrap();
+        assert_eq!(notification.entry.len(), 1);
+
+        match Notification::from_request(&req, "OTHER_SECRET") {
+            Err(NotificationError::SignatureInvalid(SignatureError::Mismatch)) => {}
+            x => panic!("{x:?}"),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use hmac_sha256::HMAC;
+use http_api_client_endpoint::Request;
+
+//
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+pub const SIGNATURE_PREFIX: &str = "sha256=";
+
+// The signature is the HMAC-SHA256 of the raw body with the app secret as key
+pub fn verify_signature(
+    req: &Request<Vec<u8>>,
+    app_secret: impl AsRef<str>,
+) -> Result<(), SignatureError> {
+    let header_value = req
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .ok_or(SignatureError::HeaderMissing)?
+        .to_str()
+        .map_err(|_| SignatureError::HeaderInvalid)?;
+
+    let signature = header_value
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(decode_hex_32)
+        .ok_or(SignatureError::HeaderInvalid)?;
+
+    if HMAC::verify(req.body(), app_secret.as_ref(), &signature) {
+        Ok(())
+    } else {
+        Err(SignatureError::Mismatch)
+    }
+}
+
+fn decode_hex_32(s: &str) -> Option<[u8; 32]> {
+    let s = s.as_bytes();
+    if s.len() != 64 {
+        return None;
+    }
+    let mut bytes = [0; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        let hi = (s[i * 2] as char).to_digit(16)?;
+        let lo = (s[i * 2 + 1] as char).to_digit(16)?;
+        *byte = (hi * 16 + lo) as u8;
+    }
+    Some(bytes)
+}
+
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    HeaderMissing,
+    HeaderInvalid,
+    Mismatch,
+}
+
+impl core::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use http_api_client_endpoint::http::Method;
+
+    pub(crate) fn signed_request(body: &[u8], app_secret: &str) -> Request<Vec<u8>> {
+        let signature = HMAC::mac(body, app_secret)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+        Request::builder()
+            .method(Method::POST)
+            .uri("/webhooks")
+            .header(SIGNATURE_HEADER, format!("{SIGNATURE_PREFIX}{signature}"))
+            .body(body.to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        // echo -n '{"object":"instagram","entry":[]}' | openssl dgst -sha256 -hmac APP_SECRET
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/webhooks")
+            .header(
+                SIGNATURE_HEADER,
+                "sha256=8676ea9548f81d17ede4bea14104f0435f606d87ba5b53b3a0a238987b4fb7d4",
+            )
+            .body(br#"{"object":"instagram","entry":[]}"#.to_vec())
+            .unwrap();
+        assert_eq!(verify_signature(&req, "APP_SECRET"), Ok(()));
+
+        let req = signed_request(br#"{"object":"instagram","entry":[]}"#, "APP_SECRET");
+        assert_eq!(verify_signature(&req, "APP_SECRET"), Ok(()));
+        assert_eq!(
+            verify_signature(&req, "OTHER_SECRET"),
+            Err(SignatureError::Mismatch)
+        );
+
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/webhooks")
+            .body(vec![])
+            .unwrap();
+        assert_eq!(
+            verify_signature(&req, "APP_SECRET"),
+            Err(SignatureError::HeaderMissing)
+        );
+
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/webhooks")
+            .header(SIGNATURE_HEADER, "sha1=abc")
+            .body(vec![])
+            .unwrap();
+        assert_eq!(
+            verify_signature(&req, "APP_SECRET"),
+            Err(SignatureError::HeaderInvalid)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use hmac_sha256::Hash;
+use http_api_client_endpoint::{
+    http::{header::CONTENT_TYPE, Method, StatusCode},
+    Request, Response,
+};
+use url::form_urlencoded;
+
+//
+pub const MODE_SUBSCRIBE: &str = "subscribe";
+
+// Returns hub.challenge, it must be echoed back in the response body
+pub fn verify_subscription(
+    req: &Request<Vec<u8>>,
+    verify_token: impl AsRef<str>,
+) -> Result<Box<str>, VerificationError> {
+    if req.method() != Method::GET {
+        return Err(VerificationError::MethodMismatch);
+    }
+
+    let mut mode = None;
+    let mut token = None;
+    let mut challenge = None;
+    for (k, v) in form_urlencoded::parse(req.uri().query().unwrap_or_default().as_bytes()) {
+        match k.as_ref() {
+            "hub.mode" => mode = Some(v),
+            "hub.verify_token" => token = Some(v),
+            "hub.challenge" => challenge = Some(v),
+            _ => {}
+        }
+    }
+
+    if mode.as_deref() != Some(MODE_SUBSCRIBE) {
+        return Err(VerificationError::ModeMismatch);
+    }
+    match token {
+        Some(token) if constant_time_eq(&token, verify_token.as_ref()) => {}
+        _ => return Err(VerificationError::VerifyTokenMismatch),
+    }
+    challenge
+        .map(|x| x.as_ref().into())
+        .ok_or(VerificationError::ChallengeMissing)
+}
+
+// Digests first, so the time does not depend on the lengths either
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    Hash::hash(a.as_bytes())
+        .iter()
+        .zip(Hash::hash(b.as_bytes()).iter())
+        .fold(0, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+// 200 with the challenge, or 403
+pub fn respond_subscription(
+    req: &Request<Vec<u8>>,
+    verify_token: impl AsRef<str>,
+) -> Response<Vec<u8>> {
+    let (status, body) = match verify_subscription(req, verify_token) {
+        Ok(challenge) => (StatusCode::OK, challenge.as_bytes().to_vec()),
+        Err(_) => (StatusCode::FORBIDDEN, vec![]),
+    };
+
+    let mut res = Response::new(body);
+    *res.status_mut() = status;
+    res.headers_mut()
+        .insert(CONTENT_TYPE, "text/plain".parse().expect("Never"));
+    res
+}
+
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationError {
+    MethodMismatch,
+    ModeMismatch,
+    VerifyTokenMismatch,
+    ChallengeMissing,
+}
+
+impl core::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(uri: &str) -> Request<Vec<u8>> {
+        Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(vec![])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_subscription() {
+        let req = request(
+            "/webhooks?hub.mode=subscribe&hub.challenge=1158201444&hub.verify_token=meatyhamhock",
+        );
+        assert_eq!(
+            verify_subscription(&req, "meatyhamhock").unwrap().as_ref(),
+            "1158201444"
+        );
+        assert_eq!(
+            verify_subscription(&req, "foo"),
+            Err(VerificationError::VerifyTokenMismatch)
+        );
+        assert_eq!(
+            verify_subscription(&req, "meatyhamhockx"),
+            Err(VerificationError::VerifyTokenMismatch)
+        );
+
+        let req = request("/webhooks?hub.mode=subscribe&hub.challenge=1");
+        assert_eq!(
+            verify_subscription(&req, ""),
+            Err(VerificationError::VerifyTokenMismatch)
+        );
+
+        let req = request("/webhooks?hub.mode=unsubscribe&hub.challenge=1&hub.verify_token=foo");
+        assert_eq!(
+            verify_subscription(&req, "foo"),
+            Err(VerificationError::ModeMismatch)
+        );
+
+        let req = request("/webhooks?hub.mode=subscribe&hub.verify_token=foo");
+        assert_eq!(
+            verify_subscription(&req, "foo"),
+            Err(VerificationError::ChallengeMissing)
+        );
+
+        let req = request("/webhooks");
+        assert_eq!(
+            verify_subscription(&req, "foo"),
+            Err(VerificationError::ModeMismatch)
+        );
+    }
+
+    #[test]
+    fn test_respond_subscription() {
+        let req = request(
+            "/webhooks?hub.mode=subscribe&hub.challenge=1158201444&hub.verify_token=meatyhamhock",
+        );
+        let res = respond_subscription(&req, "meatyhamhock");
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.body(), b"1158201444");
+
+        let res = respond_subscription(&req, "foo");
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+        assert!(res.body().is_empty());
+    }
+}
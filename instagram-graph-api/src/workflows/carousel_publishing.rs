@@ -0,0 +1,550 @@
+use core::{future::Future, pin::Pin, task::Poll, time::Duration};
+use std::sync::Mutex;
+
+use http_api_client::{Client, RetryableClient};
+
+use crate::{
+    operations::{
+        ig_user::media::creating::ValueUserTag, IgUserMediaCreatingWithCarousel,
+        IgUserMediaCreatingWithCarouselItemImage, IgUserMediaCreatingWithCarouselItemVideo,
+    },
+    workflows::publishing::{Publishing, PublishingError},
+};
+
+//
+pub const ITEMS_MIN: usize = 2;
+pub const ITEMS_MAX: usize = 10;
+pub const CONCURRENCY_DEFAULT: usize = 3;
+
+//
+#[derive(Debug, Clone)]
+pub struct CarouselPublishing {
+    pub publishing: Publishing,
+    pub items: Vec<CarouselItem>,
+    pub caption: Option<Box<str>>,
+    pub location_id: Option<u64>,
+    pub share_to_feed: Option<bool>,
+    // Usernames, max 3
+    pub collaborators: Option<Vec<Box<str>>>,
+    // Max number of children being created at the same time
+    pub concurrency: usize,
+}
+
+impl CarouselPublishing {
+    pub fn new(publishing: Publishing, items: Vec<CarouselItem>) -> Self {
+        Self {
+            publishing,
+            items,
+            caption: None,
+            location_id: None,
+            share_to_feed: None,
+            collaborators: None,
+            concurrency: CONCURRENCY_DEFAULT,
+        }
+    }
+
+    pub fn caption(mut self, value: impl AsRef<str>) -> Self {
+        self.caption = Some(value.as_ref().into());
+        self
+    }
+
+    pub fn location_id(mut self, value: u64) -> Self {
+        self.location_id = Some(value);
+        self
+    }
+
+    pub fn share_to_feed(mut self, value: bool) -> Self {
+        self.share_to_feed = Some(value);
+        self
+    }
+
+    pub fn collaborators(mut self, value: Vec<impl AsRef<str>>) -> Self {
+        self.collaborators = Some(value.iter().map(|x| x.as_ref().into()).collect());
+        self
+    }
+
+    pub fn concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value;
+        self
+    }
+
+    // Create and wait for every child, then create and publish the carousel. Returns the IG Media id.
+    pub async fn publish<C>(
+        &self,
+        client: &C,
+    ) -> Result<u64, CarouselPublishingError<C::RespondError>>
+    where
+        C: RetryableClient + Send + Sync,
+    {
+        self.publish_with_sleep(client, |dur| client.sleep(dur))
+            .await
+    }
+
+    pub async fn publish_with_sleep<C, S, SF>(
+        &self,
+        client: &C,
+        sleep: S,
+    ) -> Result<u64, CarouselPublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+        S: Fn(Duration) -> SF,
+        SF: Future<Output = ()>,
+    {
+        if !(ITEMS_MIN..=ITEMS_MAX).contains(&self.items.len()) {
+            return Err(CarouselPublishingError::ItemsCountInvalid(self.items.len()));
+        }
+
+        // Filled as soon as each child container is created
+        let created_children = Mutex::new(vec![None; self.items.len()]);
+
+        let children = try_join_bounded(
+            self.items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| self.create_child(client, &sleep, item, i, &created_children))
+                .collect(),
+            self.concurrency.max(1),
+        )
+        .await
+        .map_err(|(index, err, _)| {
+            let created_children = created_children.lock().expect("Never").clone();
+            CarouselPublishingError::ChildFailed {
+                index,
+                ig_container_id: created_children[index],
+                created_children,
+                err,
+            }
+        })?;
+
+        let mut creating = IgUserMediaCreatingWithCarousel::new(
+            self.publishing.ig_user_id,
+            children.clone(),
+            &self.publishing.access_token,
+            self.publishing.version.clone(),
+        );
+        creating.caption = self.caption.clone();
+        creating.location_id = self.location_id;
+        creating.share_to_feed = self.share_to_feed;
+        creating.collaborators = self.collaborators.clone();
+
+        self.publishing
+            .publish_with_sleep(client, sleep, &creating)
+            .await
+            .map_err(|err| CarouselPublishingError::CarouselFailed { children, err })
+    }
+
+    async fn create_child<C, S, SF>(
+        &self,
+        client: &C,
+        sleep: &S,
+        item: &CarouselItem,
+        index: usize,
+        created_children: &Mutex<Vec<Option<u64>>>,
+    ) -> Result<u64, PublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+        S: Fn(Duration) -> SF,
+        SF: Future<Output = ()>,
+    {
+        let ig_user_id = self.publishing.ig_user_id;
+        let access_token = &self.publishing.access_token;
+        let version = self.publishing.version.clone();
+
+        let ig_container_id = match item {
+            CarouselItem::Image {
+                image_url,
+                user_tags,
+            } => {
+                let mut creating = IgUserMediaCreatingWithCarouselItemImage::new(
+                    ig_user_id,
+                    image_url,
+                    access_token,
+                    version,
+                );
+                creating.user_tags = user_tags.clone();
+                self.publishing.create_container(client, &creating).await
+            }
+            CarouselItem::Video {
+                video_url,
+                user_tags,
+                thumb_offset,
+            } => {
+                let mut creating = IgUserMediaCreatingWithCarouselItemVideo::new(
+                    ig_user_id,
+                    video_url,
+                    access_token,
+                    version,
+                );
+                creating.user_tags = user_tags.clone();
+                creating.thumb_offset = *thumb_offset;
+                self.publishing.create_container(client, &creating).await
+            }
+        }?;
+        created_children.lock().expect("Never")[index] = Some(ig_container_id);
+
+        self.publishing
+            .wait_container_with_sleep(client, sleep, ig_container_id)
+            .await?;
+
+        Ok(ig_container_id)
+    }
+}
+
+//
+#[derive(Debug, Clone)]
+pub enum CarouselItem {
+    Image {
+        image_url: Box<str>,
+        user_tags: Option<Vec<ValueUserTag>>,
+    },
+    Video {
+        video_url: Box<str>,
+        user_tags: Option<Vec<ValueUserTag>>,
+        thumb_offset: Option<u64>,
+    },
+}
+
+impl CarouselItem {
+    pub fn image(
+        image_url: impl AsRef<str>,
+        user_tags: impl Into<Option<Vec<ValueUserTag>>>,
+    ) -> Self {
+        Self::Image {
+            image_url: image_url.as_ref().into(),
+            user_tags: user_tags.into(),
+        }
+    }
+
+    pub fn video(
+        video_url: impl AsRef<str>,
+        user_tags: impl Into<Option<Vec<ValueUserTag>>>,
+        thumb_offset: impl Into<Option<u64>>,
+    ) -> Self {
+        Self::Video {
+            video_url: video_url.as_ref().into(),
+            user_tags: user_tags.into(),
+            thumb_offset: thumb_offset.into(),
+        }
+    }
+}
+
+//
+#[derive(Debug)]
+pub enum CarouselPublishingError<RE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+{
+    ItemsCountInvalid(usize),
+    // Children containers are not deletable, the created ones expire after 24 hours
+    ChildFailed {
+        index: usize,
+        ig_container_id: Option<u64>,
+        // Indexed by item, None if not created.
+        // Includes the children still being processed when the error happened,
+        // not the ones whose creating request was still in flight.
+        created_children: Vec<Option<u64>>,
+        err: PublishingError<RE>,
+    },
+    CarouselFailed {
+        children: Vec<u64>,
+        err: PublishingError<RE>,
+    },
+}
+
+impl<RE> core::fmt::Display for CarouselPublishingError<RE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl<RE> std::error::Error for CarouselPublishingError<RE> where
+    RE: std::error::Error + Send + Sync + 'static
+{
+}
+
+// Run at most `limit` futures at the same time, stop at the first error.
+// The error comes with its index and the outputs of the futures completed so far, indexed as the futures.
+async fn try_join_bounded<Fut, T, E>(
+    futs: Vec<Fut>,
+    limit: usize,
+) -> Result<Vec<T>, (usize, E, Vec<Option<T>>)>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut outputs: Vec<Option<T>> = futs.iter().map(|_| None).collect();
+    let mut pending = futs.into_iter().enumerate();
+    let mut running: Vec<(usize, Pin<Box<Fut>>)> = Vec::with_capacity(limit);
+
+    let ret = core::future::poll_fn(|cx| loop {
+        while running.len() < limit {
+            match pending.next() {
+                Some((i, fut)) => running.push((i, Box::pin(fut))),
+                None => break,
+            }
+        }
+        if running.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+
+        let mut progressed = false;
+        let mut j = 0;
+        while j < running.len() {
+            match running[j].1.as_mut().poll(cx) {
+                Poll::Ready(Ok(output)) => {
+                    outputs[running[j].0] = Some(output);
+                    running.swap_remove(j);
+                    progressed = true;
+                }
+                Poll::Ready(Err(err)) => return Poll::Ready(Err((running[j].0, err))),
+                Poll::Pending => j += 1,
+            }
+        }
+        if !progressed {
+            return Poll::Pending;
+        }
+    })
+    .await;
+
+    match ret {
+        Ok(()) => Ok(outputs.into_iter().flatten().collect()),
+        Err((index, err)) => Err((index, err, outputs)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use http_api_client_endpoint::http::StatusCode;
+
+    use crate::workflows::publishing::tests::MockClient;
+
+    const FINISHED_JSON: &str = r#"{"id":"1001","status":"Finished","status_code":"FINISHED"}"#;
+
+    #[test]
+    fn test_publish() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (StatusCode::OK, FINISHED_JSON),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"Finished","status_code":"FINISHED"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1003","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1003","status":"Finished","status_code":"FINISHED"}"#,
+                ),
+                (StatusCode::OK, r#"{"id":"2001"}"#),
+            ]);
+
+            let carousel_publishing = CarouselPublishing::new(
+                Publishing::new(1, "TOKEN", None),
+                vec![
+                    CarouselItem::image(
+                        "https://example.com/1.jpg",
+                        vec![ValueUserTag::new(0.5, 0.5, "foo")],
+                    ),
+                    CarouselItem::video("https://example.com/2.mp4", None, 10),
+                ],
+            )
+            .caption("bar")
+            .concurrency(1);
+            assert_eq!(carousel_publishing.publish(&client).await.unwrap(), 2001);
+
+            let requests = client.requests.lock().unwrap();
+            assert_eq!(requests.len(), 7);
+            assert_eq!(
+                requests[0].uri().path_and_query().unwrap(),
+                "/v15.0/1/media?fields=id,status,status_code&image_url=https%3A%2F%2Fexample.com%2F1.jpg&is_carousel_item=true&user_tags=%5B%7B%22x%22%3A0.5%2C%22y%22%3A0.5%2C%22username%22%3A%22foo%22%7D%5D&access_token=TOKEN"
+            );
+            assert_eq!(
+                requests[2].uri().path_and_query().unwrap(),
+                "/v15.0/1/media?fields=id,status,status_code&media_type=VIDEO&video_url=https%3A%2F%2Fexample.com%2F2.mp4&is_carousel_item=true&thumb_offset=10&access_token=TOKEN"
+            );
+            assert_eq!(
+                requests[4].uri().path_and_query().unwrap(),
+                "/v15.0/1/media?fields=id,status,status_code&media_type=CAROUSEL&children=%5B1001%2C1002%5D&caption=bar&access_token=TOKEN"
+            );
+        })
+    }
+
+    #[test]
+    fn test_publish_with_items_count_invalid() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![]);
+
+            for n in [0, 1, 11] {
+                let carousel_publishing = CarouselPublishing::new(
+                    Publishing::new(1, "TOKEN", None),
+                    (0..n)
+                        .map(|_| CarouselItem::image("https://example.com/1.jpg", None))
+                        .collect(),
+                );
+                match carousel_publishing.publish(&client).await {
+                    Err(CarouselPublishingError::ItemsCountInvalid(x)) => assert_eq!(x, n),
+                    x => panic!("{x:?}"),
+                }
+            }
+
+            assert!(client.requests.lock().unwrap().is_empty());
+        })
+    }
+
+    #[test]
+    fn test_publish_with_child_failed() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![
+                (StatusCode::OK, FINISHED_JSON),
+                (StatusCode::OK, FINISHED_JSON),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"Error: Media upload has failed with error code 2207026","status_code":"ERROR"}"#,
+                ),
+            ]);
+
+            let carousel_publishing = CarouselPublishing::new(
+                Publishing::new(1, "TOKEN", None),
+                vec![
+                    CarouselItem::image("https://example.com/1.jpg", None),
+                    CarouselItem::video("https://example.com/2.mp4", None, None),
+                    CarouselItem::image("https://example.com/3.jpg", None),
+                ],
+            )
+            .concurrency(1);
+            match carousel_publishing.publish(&client).await {
+                Err(CarouselPublishingError::ChildFailed {
+                    index,
+                    ig_container_id,
+                    created_children,
+                    err: PublishingError::ContainerError { .. },
+                }) => {
+                    assert_eq!(index, 1);
+                    assert_eq!(ig_container_id, Some(1002));
+                    assert_eq!(created_children, vec![Some(1001), Some(1002), None]);
+                }
+                x => panic!("{x:?}"),
+            }
+
+            assert_eq!(client.requests.lock().unwrap().len(), 4);
+        })
+    }
+
+    #[test]
+    fn test_publish_with_child_failed_and_in_flight() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"In Progress","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1002","status":"Error: Media upload has failed with error code 2207026","status_code":"ERROR"}"#,
+                ),
+            ]);
+
+            let carousel_publishing = CarouselPublishing::new(
+                Publishing::new(1, "TOKEN", None),
+                vec![
+                    CarouselItem::image("https://example.com/1.jpg", None),
+                    CarouselItem::image("https://example.com/2.jpg", None),
+                    CarouselItem::image("https://example.com/3.jpg", None),
+                ],
+            )
+            .concurrency(2);
+            match carousel_publishing
+                .publish_with_sleep(&client, |_| futures_lite::future::yield_now())
+                .await
+            {
+                Err(CarouselPublishingError::ChildFailed {
+                    index,
+                    ig_container_id,
+                    created_children,
+                    err: PublishingError::ContainerError { .. },
+                }) => {
+                    assert_eq!(index, 1);
+                    assert_eq!(ig_container_id, Some(1002));
+                    // 1001 was still in progress
+                    assert_eq!(created_children, vec![Some(1001), Some(1002), None]);
+                }
+                x => panic!("{x:?}"),
+            }
+        })
+    }
+
+    #[test]
+    fn test_try_join_bounded() {
+        futures_lite::future::block_on(async {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+
+            let running = AtomicUsize::new(0);
+            let running_max = AtomicUsize::new(0);
+            let ret = try_join_bounded(
+                (0..5)
+                    .map(|i| {
+                        let running = &running;
+                        let running_max = &running_max;
+                        async move {
+                            let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                            running_max.fetch_max(n, Ordering::SeqCst);
+                            for _ in 0..(5 - i) {
+                                futures_lite::future::yield_now().await;
+                            }
+                            running.fetch_sub(1, Ordering::SeqCst);
+                            Ok::<_, ()>(i)
+                        }
+                    })
+                    .collect(),
+                2,
+            )
+            .await;
+            assert_eq!(ret, Ok(vec![0, 1, 2, 3, 4]));
+            assert_eq!(running_max.load(Ordering::SeqCst), 2);
+
+            let ret = try_join_bounded(
+                (0..5)
+                    .map(|i| async move {
+                        if i == 3 {
+                            Err(i)
+                        } else {
+                            Ok(i)
+                        }
+                    })
+                    .collect(),
+                2,
+            )
+            .await;
+            assert_eq!(
+                ret,
+                Err((3, 3, vec![Some(0), Some(1), Some(2), None, None]))
+            );
+        })
+    }
+}
@@ -0,0 +1,6 @@
+//
+pub mod carousel_publishing;
+pub mod publishing;
+
+pub use carousel_publishing::{CarouselItem, CarouselPublishing, CarouselPublishingError};
+pub use publishing::{Publishing, PublishingError, PublishingStep};
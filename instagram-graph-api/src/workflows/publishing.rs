@@ -0,0 +1,602 @@
+use core::{future::Future, time::Duration};
+use std::time::Instant;
+
+use http_api_client::{Client, ClientRespondEndpointError, RetryableClient};
+use http_api_client_endpoint::{http::StatusCode, Body, Endpoint};
+
+use crate::{
+    objects::{IgContainer, IgContainerStatusCode, ResponseBodyErrJson},
+    operations::{
+        common::{EndpointError, EndpointRet},
+        ig_user::media::creating::{CreatingResponseBodyRet, ExtInfoError},
+        IgContainerReading, IgUserMediaPublishCreating,
+    },
+    types::IgAccessToken,
+};
+
+//
+pub const POLL_INTERVAL_DEFAULT: Duration = Duration::from_secs(2);
+pub const POLL_INTERVAL_MIN: Duration = Duration::from_secs(1);
+pub const POLL_INTERVAL_MAX_DEFAULT: Duration = Duration::from_secs(60);
+pub const BACKOFF_MULTIPLIER_DEFAULT: u32 = 2;
+// Containers not published within 24 hours expire, but Meta recommends giving up after 5 minutes
+pub const TIMEOUT_DEFAULT: Duration = Duration::from_secs(5 * 60);
+
+//
+#[derive(Debug, Clone)]
+pub struct Publishing {
+    pub ig_user_id: u64,
+    pub poll_interval: Duration,
+    pub poll_interval_max: Duration,
+    pub backoff_multiplier: u32,
+    // Total time spent waiting for the container, requests included
+    pub timeout: Duration,
+    //
+    pub access_token: Box<str>,
+    pub version: Option<Box<str>>,
+}
+
+impl Publishing {
+    pub fn new(
+        ig_user_id: u64,
+        access_token: impl IgAccessToken,
+        version: impl Into<Option<Box<str>>>,
+    ) -> Self {
+        Self {
+            ig_user_id,
+            poll_interval: POLL_INTERVAL_DEFAULT,
+            poll_interval_max: POLL_INTERVAL_MAX_DEFAULT,
+            backoff_multiplier: BACKOFF_MULTIPLIER_DEFAULT,
+            timeout: TIMEOUT_DEFAULT,
+            access_token: access_token.as_ref().into(),
+            version: version.into(),
+        }
+    }
+
+    // Clamped to POLL_INTERVAL_MIN
+    pub fn poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value.max(POLL_INTERVAL_MIN);
+        self
+    }
+
+    // Clamped to POLL_INTERVAL_MIN
+    pub fn poll_interval_max(mut self, value: Duration) -> Self {
+        self.poll_interval_max = value.max(POLL_INTERVAL_MIN);
+        self
+    }
+
+    // Clamped to 1, which means no backoff
+    pub fn backoff_multiplier(mut self, value: u32) -> Self {
+        self.backoff_multiplier = value.max(1);
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    // Create the container, wait until it is finished, then publish it. Returns the IG Media id.
+    pub async fn publish<C, EP>(
+        &self,
+        client: &C,
+        creating: &EP,
+    ) -> Result<u64, PublishingError<C::RespondError>>
+    where
+        C: RetryableClient + Send + Sync,
+        EP: Endpoint<
+                RenderRequestError = EndpointError,
+                ParseResponseOutput = EndpointRet<CreatingResponseBodyRet>,
+                ParseResponseError = EndpointError,
+            > + Send
+            + Sync,
+    {
+        self.publish_with_sleep(client, |dur| client.sleep(dur), creating)
+            .await
+    }
+
+    // For clients without RetryableClient, e.g. sleep is |dur| tokio::time::sleep(dur)
+    pub async fn publish_with_sleep<C, S, SF, EP>(
+        &self,
+        client: &C,
+        sleep: S,
+        creating: &EP,
+    ) -> Result<u64, PublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+        S: Fn(Duration) -> SF,
+        SF: Future<Output = ()>,
+        EP: Endpoint<
+                RenderRequestError = EndpointError,
+                ParseResponseOutput = EndpointRet<CreatingResponseBodyRet>,
+                ParseResponseError = EndpointError,
+            > + Send
+            + Sync,
+    {
+        let ig_container_id = self.create_container(client, creating).await?;
+        self.wait_container_with_sleep(client, sleep, ig_container_id)
+            .await?;
+        self.publish_container(client, ig_container_id).await
+    }
+
+    pub async fn create_container<C, EP>(
+        &self,
+        client: &C,
+        creating: &EP,
+    ) -> Result<u64, PublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+        EP: Endpoint<
+                RenderRequestError = EndpointError,
+                ParseResponseOutput = EndpointRet<CreatingResponseBodyRet>,
+                ParseResponseError = EndpointError,
+            > + Send
+            + Sync,
+    {
+        let ret = client
+            .respond_endpoint(creating)
+            .await
+            .map_err(|err| PublishingError::RespondEndpointFailed(PublishingStep::Creating, err))?;
+
+        match ret {
+            EndpointRet::Ok(CreatingResponseBodyRet::OkJson(ok_json)) => Ok(ok_json.id),
+            EndpointRet::Ok(CreatingResponseBodyRet::ExtInfoError(err)) => {
+                Err(PublishingError::CreatingExtInfoError(err))
+            }
+            EndpointRet::Other((status, body)) => Err(PublishingError::EndpointRetOther(
+                PublishingStep::Creating,
+                status,
+                body,
+            )),
+        }
+    }
+
+    // Poll the container status with backoff until it is FINISHED
+    pub async fn wait_container<C>(
+        &self,
+        client: &C,
+        ig_container_id: u64,
+    ) -> Result<IgContainer, PublishingError<C::RespondError>>
+    where
+        C: RetryableClient + Send + Sync,
+    {
+        self.wait_container_with_sleep(client, |dur| client.sleep(dur), ig_container_id)
+            .await
+    }
+
+    pub async fn wait_container_with_sleep<C, S, SF>(
+        &self,
+        client: &C,
+        sleep: S,
+        ig_container_id: u64,
+    ) -> Result<IgContainer, PublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+        S: Fn(Duration) -> SF,
+        SF: Future<Output = ()>,
+    {
+        let reading =
+            IgContainerReading::new(ig_container_id, &self.access_token, self.version.clone());
+
+        let backoff_multiplier = self.backoff_multiplier.max(1);
+        let interval_max = self.poll_interval_max.max(POLL_INTERVAL_MIN);
+        let mut interval = self.poll_interval.max(POLL_INTERVAL_MIN).min(interval_max);
+        let start = Instant::now();
+        // A mocked sleep does not move the clock, so slept time counts too
+        let mut slept = Duration::ZERO;
+        loop {
+            let ret = client.respond_endpoint(&reading).await.map_err(|err| {
+                PublishingError::RespondEndpointFailed(PublishingStep::ContainerReading, err)
+            })?;
+
+            let container = match ret {
+                EndpointRet::Ok(ok_json) => ok_json.0,
+                EndpointRet::Other((status, body)) => {
+                    return Err(PublishingError::EndpointRetOther(
+                        PublishingStep::ContainerReading,
+                        status,
+                        body,
+                    ))
+                }
+            };
+
+            match container.status_code {
+                IgContainerStatusCode::Finished => return Ok(container),
+                IgContainerStatusCode::InProgress => {}
+                IgContainerStatusCode::Error => {
+                    return Err(PublishingError::ContainerError {
+                        ig_container_id,
+                        status: container.status,
+                    })
+                }
+                IgContainerStatusCode::Expired => {
+                    return Err(PublishingError::ContainerExpired {
+                        ig_container_id,
+                        status: container.status,
+                    })
+                }
+                IgContainerStatusCode::Published => {
+                    return Err(PublishingError::ContainerPublished { ig_container_id })
+                }
+            }
+
+            let elapsed = start.elapsed().max(slept);
+            if elapsed >= self.timeout {
+                return Err(PublishingError::Timeout {
+                    ig_container_id,
+                    status: container.status,
+                });
+            }
+
+            let dur = interval.min(self.timeout - elapsed);
+            sleep(dur).await;
+            slept += dur;
+
+            interval = interval
+                .saturating_mul(backoff_multiplier)
+                .min(interval_max);
+        }
+    }
+
+    pub async fn publish_container<C>(
+        &self,
+        client: &C,
+        ig_container_id: u64,
+    ) -> Result<u64, PublishingError<C::RespondError>>
+    where
+        C: Client + Send + Sync,
+    {
+        let creating = IgUserMediaPublishCreating::new(
+            self.ig_user_id,
+            ig_container_id,
+            &self.access_token,
+            self.version.clone(),
+        );
+
+        let ret = client.respond_endpoint(&creating).await.map_err(|err| {
+            PublishingError::RespondEndpointFailed(PublishingStep::MediaPublishCreating, err)
+        })?;
+
+        match ret {
+            EndpointRet::Ok(ok_json) => Ok(ok_json.id),
+            EndpointRet::Other((status, body)) => Err(PublishingError::EndpointRetOther(
+                PublishingStep::MediaPublishCreating,
+                status,
+                body,
+            )),
+        }
+    }
+}
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishingStep {
+    Creating,
+    ContainerReading,
+    MediaPublishCreating,
+}
+
+//
+#[derive(Debug)]
+pub enum PublishingError<RE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+{
+    RespondEndpointFailed(
+        PublishingStep,
+        ClientRespondEndpointError<RE, EndpointError, EndpointError>,
+    ),
+    EndpointRetOther(
+        PublishingStep,
+        StatusCode,
+        Result<ResponseBodyErrJson, Body>,
+    ),
+    CreatingExtInfoError(ExtInfoError),
+    // status e.g. "Error: Media upload has failed with error code 2207026"
+    ContainerError {
+        ig_container_id: u64,
+        status: Box<str>,
+    },
+    ContainerExpired {
+        ig_container_id: u64,
+        status: Box<str>,
+    },
+    ContainerPublished {
+        ig_container_id: u64,
+    },
+    Timeout {
+        ig_container_id: u64,
+        status: Box<str>,
+    },
+}
+
+impl<RE> core::fmt::Display for PublishingError<RE>
+where
+    RE: std::error::Error + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl<RE> std::error::Error for PublishingError<RE> where
+    RE: std::error::Error + Send + Sync + 'static
+{
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use std::{collections::VecDeque, io::Error as IoError, sync::Mutex};
+
+    use http_api_client::{async_trait, Client};
+    use http_api_client_endpoint::{Request, Response};
+
+    use crate::operations::IgUserMediaCreatingWithImage;
+
+    //
+    #[derive(Debug, Default)]
+    pub(crate) struct MockClient {
+        pub(crate) responses: Mutex<VecDeque<(StatusCode, &'static str)>>,
+        pub(crate) requests: Mutex<Vec<Request<Body>>>,
+        pub(crate) sleeps: Mutex<Vec<Duration>>,
+    }
+
+    impl MockClient {
+        pub(crate) fn new(responses: Vec<(StatusCode, &'static str)>) -> Self {
+            Self {
+                responses: Mutex::new(responses.into()),
+                ..Default::default()
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Client for MockClient {
+        type RespondError = IoError;
+
+        async fn respond(&self, request: Request<Body>) -> Result<Response<Body>, IoError> {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| IoError::other("no more responses"))?;
+            Ok(Response::builder()
+                .status(status)
+                .body(body.as_bytes().to_vec())
+                .unwrap())
+        }
+    }
+
+    #[async_trait]
+    impl RetryableClient for MockClient {
+        async fn sleep(&self, dur: Duration) {
+            self.sleeps.lock().unwrap().push(dur);
+        }
+    }
+
+    pub(crate) const CREATING_OK_JSON: &str = r#"{"id":"1001","status":"In Progress: Media is still being processed.","status_code":"IN_PROGRESS"}"#;
+
+    #[test]
+    fn test_publish() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![
+                (StatusCode::OK, CREATING_OK_JSON),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"In Progress: Media is still being processed.","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"In Progress: Media is still being processed.","status_code":"IN_PROGRESS"}"#,
+                ),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"Finished: Media has been uploaded and it is ready to be published.","status_code":"FINISHED"}"#,
+                ),
+                (StatusCode::OK, r#"{"id":"2001"}"#),
+            ]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let publishing = Publishing::new(1, "TOKEN", None);
+            assert_eq!(publishing.publish(&client, &creating).await.unwrap(), 2001);
+
+            assert_eq!(
+                *client.sleeps.lock().unwrap(),
+                vec![Duration::from_secs(2), Duration::from_secs(4)]
+            );
+            let requests = client.requests.lock().unwrap();
+            assert_eq!(requests.len(), 5);
+            assert_eq!(
+                requests[4].uri().path_and_query().unwrap(),
+                "/v15.0/1/media_publish?creation_id=1001&access_token=TOKEN"
+            );
+        })
+    }
+
+    #[test]
+    fn test_publish_with_container_error() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![
+                (StatusCode::OK, CREATING_OK_JSON),
+                (
+                    StatusCode::OK,
+                    r#"{"id":"1001","status":"Error: Media upload has failed with error code 2207026","status_code":"ERROR"}"#,
+                ),
+            ]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let publishing = Publishing::new(1, "TOKEN", None);
+            match publishing.publish(&client, &creating).await {
+                Err(PublishingError::ContainerError {
+                    ig_container_id,
+                    status,
+                }) => {
+                    assert_eq!(ig_container_id, 1001);
+                    assert_eq!(
+                        status.as_ref(),
+                        "Error: Media upload has failed with error code 2207026"
+                    );
+                }
+                x => panic!("{x:?}"),
+            }
+        })
+    }
+
+    #[test]
+    fn test_publish_with_timeout() {
+        futures_lite::future::block_on(async {
+            let in_progress = r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#;
+            let client = MockClient::new(vec![
+                (StatusCode::OK, CREATING_OK_JSON),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+            ]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let publishing = Publishing::new(1, "TOKEN", None)
+                .poll_interval(Duration::from_secs(1))
+                .backoff_multiplier(3)
+                .poll_interval_max(Duration::from_secs(5))
+                .timeout(Duration::from_secs(8));
+            match publishing.publish(&client, &creating).await {
+                Err(PublishingError::Timeout {
+                    ig_container_id, ..
+                }) => {
+                    assert_eq!(ig_container_id, 1001);
+                }
+                x => panic!("{x:?}"),
+            }
+
+            assert_eq!(
+                *client.sleeps.lock().unwrap(),
+                vec![
+                    Duration::from_secs(1),
+                    Duration::from_secs(3),
+                    Duration::from_secs(4)
+                ]
+            );
+        })
+    }
+
+    #[test]
+    fn test_publish_with_zero_poll_interval() {
+        futures_lite::future::block_on(async {
+            let in_progress = r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#;
+            let client = MockClient::new(vec![
+                (StatusCode::OK, CREATING_OK_JSON),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+            ]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let mut publishing = Publishing::new(1, "TOKEN", None)
+                .poll_interval(Duration::ZERO)
+                .backoff_multiplier(0)
+                .timeout(Duration::from_secs(3));
+            assert_eq!(publishing.poll_interval, POLL_INTERVAL_MIN);
+            assert_eq!(publishing.backoff_multiplier, 1);
+            // Set directly, bypassing the setters
+            publishing.poll_interval = Duration::ZERO;
+            publishing.backoff_multiplier = 0;
+
+            let sleeps = Mutex::new(vec![]);
+            let ret = publishing
+                .publish_with_sleep(
+                    &client,
+                    |dur| {
+                        sleeps.lock().unwrap().push(dur);
+                        async {}
+                    },
+                    &creating,
+                )
+                .await;
+            match ret {
+                Err(PublishingError::Timeout {
+                    ig_container_id, ..
+                }) => {
+                    assert_eq!(ig_container_id, 1001);
+                }
+                x => panic!("{x:?}"),
+            }
+
+            assert_eq!(*sleeps.lock().unwrap(), vec![Duration::from_secs(1); 3]);
+            assert!(client.sleeps.lock().unwrap().is_empty());
+            assert_eq!(client.requests.lock().unwrap().len(), 5);
+        })
+    }
+
+    #[test]
+    fn test_publish_with_zero_poll_interval_max() {
+        futures_lite::future::block_on(async {
+            let in_progress = r#"{"id":"1001","status":"In Progress","status_code":"IN_PROGRESS"}"#;
+            let client = MockClient::new(vec![
+                (StatusCode::OK, CREATING_OK_JSON),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+                (StatusCode::OK, in_progress),
+            ]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let mut publishing = Publishing::new(1, "TOKEN", None)
+                .poll_interval_max(Duration::ZERO)
+                .timeout(Duration::from_secs(3));
+            assert_eq!(publishing.poll_interval_max, POLL_INTERVAL_MIN);
+            // Set directly, bypassing the setters
+            publishing.poll_interval_max = Duration::ZERO;
+
+            let sleeps = Mutex::new(vec![]);
+            let ret = publishing
+                .publish_with_sleep(
+                    &client,
+                    |dur| {
+                        sleeps.lock().unwrap().push(dur);
+                        async {}
+                    },
+                    &creating,
+                )
+                .await;
+            assert!(matches!(ret, Err(PublishingError::Timeout { .. })));
+
+            let sleeps = sleeps.lock().unwrap();
+            assert!(!sleeps.is_empty());
+            assert!(sleeps.iter().all(|dur| *dur >= POLL_INTERVAL_MIN));
+            assert_eq!(client.requests.lock().unwrap().len(), 5);
+        })
+    }
+
+    #[test]
+    fn test_publish_with_creating_failed() {
+        futures_lite::future::block_on(async {
+            let client = MockClient::new(vec![(
+                StatusCode::BAD_REQUEST,
+                r#"{"error":{"message":"Invalid OAuth access token.","type":"OAuthException","code":190,"fbtrace_id":"AAAA"}}"#,
+            )]);
+
+            let creating =
+                IgUserMediaCreatingWithImage::new(1, "https://example.com/1.jpg", "TOKEN", None);
+            let publishing = Publishing::new(1, "TOKEN", None);
+            match publishing.publish(&client, &creating).await {
+                Err(PublishingError::EndpointRetOther(step, status, Ok(err_json))) => {
+                    assert_eq!(step, PublishingStep::Creating);
+                    assert_eq!(status, StatusCode::BAD_REQUEST);
+                    assert_eq!(err_json.error.code, 190);
+                }
+                x => panic!("{x:?}"),
+            }
+        })
+    }
+}